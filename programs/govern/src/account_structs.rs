@@ -1,6 +1,7 @@
 //! Accounts structs for the [govern] program.
 
 use crate::*;
+use anchor_spl::token::{Token, TokenAccount};
 
 /// Accounts for [govern::create_governor].
 #[derive(Accounts)]
@@ -57,6 +58,162 @@ pub struct CreateProposal<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Accounts for [govern::create_staged_proposal].
+#[derive(Accounts)]
+#[instruction(_bump: u8, capacity: u32)]
+pub struct CreateStagedProposal<'info> {
+    /// The [Governor].
+    #[account(mut)]
+    pub governor: Account<'info, Governor>,
+    /// The [Proposal].
+    #[account(
+        init,
+        seeds = [
+            b"TribecaProposal".as_ref(),
+            governor.key().as_ref(),
+            governor.proposal_count.to_le_bytes().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = Proposal::space(vec![]) + capacity as usize,
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// Proposer of the proposal.
+    pub proposer: Signer<'info>,
+    /// Payer of the proposal.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for [govern::append_proposal_instructions].
+#[derive(Accounts)]
+pub struct AppendProposalInstructions<'info> {
+    /// The [Proposal].
+    #[account(mut)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [Proposal::proposer].
+    pub proposer: Signer<'info>,
+}
+
+/// Accounts for [govern::seal_proposal].
+#[derive(Accounts)]
+pub struct SealProposal<'info> {
+    /// The [Governor].
+    pub governor: Account<'info, Governor>,
+    /// The [Proposal].
+    #[account(mut)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [Proposal::proposer].
+    pub proposer: Signer<'info>,
+}
+
+/// Accounts for [govern::create_committed_proposal].
+#[derive(Accounts)]
+pub struct CreateCommittedProposal<'info> {
+    /// The [Governor].
+    #[account(mut)]
+    pub governor: Account<'info, Governor>,
+    /// The [Proposal].
+    #[account(
+        init,
+        seeds = [
+            b"TribecaProposal".as_ref(),
+            governor.key().as_ref(),
+            governor.proposal_count.to_le_bytes().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = Proposal::space(vec![]),
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// Proposer of the proposal.
+    pub proposer: Signer<'info>,
+    /// Payer of the proposal.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for [govern::create_optimistic_proposal].
+#[derive(Accounts)]
+#[instruction(_bump: u8, instructions: Vec<ProposalInstruction>)]
+pub struct CreateOptimisticProposal<'info> {
+    /// The [Governor].
+    #[account(mut)]
+    pub governor: Account<'info, Governor>,
+    /// The [OptimisticConfig] of the [Governor].
+    pub optimistic_config: Account<'info, OptimisticConfig>,
+    /// The [Proposal].
+    #[account(
+        init,
+        seeds = [
+            b"TribecaProposal".as_ref(),
+            governor.key().as_ref(),
+            governor.proposal_count.to_le_bytes().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = Proposal::space(instructions),
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// Proposer of the proposal. Must be one of the [OptimisticConfig::proposers].
+    pub proposer: Signer<'info>,
+    /// Payer of the proposal.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for [govern::create_multiple_choice_proposal].
+#[derive(Accounts)]
+#[instruction(_bump: u8, options: Vec<Vec<ProposalInstruction>>)]
+pub struct CreateMultipleChoiceProposal<'info> {
+    /// The [Governor].
+    #[account(mut)]
+    pub governor: Account<'info, Governor>,
+    /// The [Proposal].
+    #[account(
+        init,
+        seeds = [
+            b"TribecaProposal".as_ref(),
+            governor.key().as_ref(),
+            governor.proposal_count.to_le_bytes().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = Proposal::multiple_choice_space(&options),
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// Proposer of the proposal.
+    pub proposer: Signer<'info>,
+    /// Payer of the proposal.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for [govern::amend_proposal_instructions].
+#[derive(Accounts)]
+pub struct AmendProposalInstructions<'info> {
+    /// The [Governor].
+    pub governor: Account<'info, Governor>,
+    /// The [Proposal] to amend.
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+    /// The [Proposal::proposer].
+    pub proposer: Signer<'info>,
+    /// Payer of any additional rent of the [Proposal].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
 /// Accounts for [govern::activate_proposal].
 #[derive(Accounts)]
 pub struct ActivateProposal<'info> {
@@ -69,6 +226,43 @@ pub struct ActivateProposal<'info> {
     pub electorate: Signer<'info>,
 }
 
+/// Accounts for [govern::create_active_proposal_counter].
+#[derive(Accounts)]
+#[instruction(_bump: u8, proposer: Pubkey)]
+pub struct CreateActiveProposalCounter<'info> {
+    /// The [Governor].
+    pub governor: Account<'info, Governor>,
+    /// The [ActiveProposalCounter].
+    #[account(
+        init,
+        seeds = [
+            b"TribecaActiveProposalCounter".as_ref(),
+            governor.key().as_ref(),
+            proposer.as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + ActiveProposalCounter::LEN
+    )]
+    pub active_proposal_counter: Account<'info, ActiveProposalCounter>,
+    /// Payer of the [ActiveProposalCounter].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for [govern::release_active_proposal].
+#[derive(Accounts)]
+pub struct ReleaseActiveProposal<'info> {
+    /// The [Proposal] to release.
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+    /// The [ActiveProposalCounter] of the [Proposal::proposer].
+    #[account(mut)]
+    pub active_proposal_counter: Account<'info, ActiveProposalCounter>,
+}
+
 /// Accounts for [govern::cancel_proposal].
 #[derive(Accounts)]
 pub struct CancelProposal<'info> {
@@ -81,6 +275,23 @@ pub struct CancelProposal<'info> {
     pub proposer: Signer<'info>,
 }
 
+/// Accounts for [govern::veto_proposal].
+#[derive(Accounts)]
+pub struct VetoProposal<'info> {
+    /// The [Governor].
+    #[account(has_one = smart_wallet)]
+    pub governor: Account<'info, Governor>,
+    /// The [Proposal] to veto.
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+    /// The [Governor::guardian].
+    pub guardian: Signer<'info>,
+    /// The Smart Wallet.
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The Smart Wallet program.
+    pub smart_wallet_program: Program<'info, smart_wallet::program::SmartWallet>,
+}
+
 /// Accounts for [govern::queue_proposal].
 #[derive(Accounts)]
 pub struct QueueProposal<'info> {
@@ -167,9 +378,7 @@ pub struct CreateProposalMeta<'info> {
         ],
         bump,
         payer = payer,
-        space = 8 + std::mem::size_of::<ProposalMeta>()
-            + 4 + title.as_bytes().len()
-            + 4 + description_link.as_bytes().len()
+        space = ProposalMeta::space(&title, &description_link)
     )]
     pub proposal_meta: Box<Account<'info, ProposalMeta>>,
     /// Payer of the [ProposalMeta].
@@ -179,7 +388,24 @@ pub struct CreateProposalMeta<'info> {
     pub system_program: Program<'info, System>,
 }
 
-/// Accounts for [govern::set_governance_params] and [govern::set_electorate].
+/// Accounts for [govern::update_proposal_meta].
+#[derive(Accounts)]
+pub struct UpdateProposalMeta<'info> {
+    /// The [Proposal].
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// Proposer of the proposal.
+    pub proposer: Signer<'info>,
+    /// The [ProposalMeta].
+    #[account(mut, has_one = proposal)]
+    pub proposal_meta: Box<Account<'info, ProposalMeta>>,
+    /// Payer of any additional rent of the [ProposalMeta].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for [govern::set_governance_params], [govern::set_electorate], and [govern::set_guardian].
 #[derive(Accounts)]
 pub struct SetGovernanceParams<'info> {
     /// The [Governor]
@@ -188,3 +414,310 @@ pub struct SetGovernanceParams<'info> {
     /// The Smart Wallet.
     pub smart_wallet: Signer<'info>,
 }
+
+/// Accounts for [govern::set_proposal_deposit].
+#[derive(Accounts)]
+pub struct SetProposalDeposit<'info> {
+    /// The [Governor]
+    #[account(mut)]
+    pub governor: Account<'info, Governor>,
+    /// The Smart Wallet.
+    pub smart_wallet: Signer<'info>,
+    /// CHECK: The [Governor]'s associated token account of the deposit mint.
+    /// Only checked if deposits are enabled, since it does not exist otherwise.
+    pub deposit_vault: UncheckedAccount<'info>,
+}
+
+/// Accounts for [govern::apply_pending_params].
+#[derive(Accounts)]
+pub struct ApplyPendingParams<'info> {
+    /// The [Governor].
+    #[account(mut)]
+    pub governor: Account<'info, Governor>,
+}
+
+/// Accounts for [govern::apply_pending_optimistic_config].
+#[derive(Accounts)]
+pub struct ApplyPendingOptimisticConfig<'info> {
+    /// The [OptimisticConfig].
+    #[account(mut)]
+    pub optimistic_config: Account<'info, OptimisticConfig>,
+}
+
+/// Accounts for [govern::migrate_governor].
+#[derive(Accounts)]
+pub struct MigrateGovernor<'info> {
+    /// CHECK: The [Governor] to migrate. Validated in [MigrateGovernor::validate],
+    /// since it cannot be deserialized before the migration.
+    #[account(mut, owner = crate::ID)]
+    pub governor: UncheckedAccount<'info>,
+    /// Payer of the additional rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for [govern::migrate_proposal].
+#[derive(Accounts)]
+pub struct MigrateProposal<'info> {
+    /// The [Governor] of the [Proposal].
+    pub governor: Account<'info, Governor>,
+    /// CHECK: The [Proposal] to migrate. Validated in [MigrateProposal::validate],
+    /// since it cannot be deserialized before the migration.
+    #[account(mut, owner = crate::ID)]
+    pub proposal: UncheckedAccount<'info>,
+    /// Payer of the additional rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for [govern::migrate_vote].
+#[derive(Accounts)]
+pub struct MigrateVote<'info> {
+    /// CHECK: The [Vote] to migrate. Validated in [MigrateVote::validate],
+    /// since it cannot be deserialized before the migration.
+    #[account(mut, owner = crate::ID)]
+    pub vote: UncheckedAccount<'info>,
+    /// Payer of the additional rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for [govern::create_parameter_override].
+#[derive(Accounts)]
+#[instruction(_bump: u8, target_program_id: Pubkey)]
+pub struct CreateParameterOverride<'info> {
+    /// The [Governor].
+    pub governor: Account<'info, Governor>,
+    /// The [ParameterOverride].
+    #[account(
+        init,
+        seeds = [
+            b"TribecaParameterOverride".as_ref(),
+            governor.key().as_ref(),
+            target_program_id.as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + ParameterOverride::LEN
+    )]
+    pub parameter_override: Account<'info, ParameterOverride>,
+    /// The Smart Wallet.
+    pub smart_wallet: Signer<'info>,
+    /// Payer of the [ParameterOverride].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for [govern::create_optimistic_config].
+#[derive(Accounts)]
+pub struct CreateOptimisticConfig<'info> {
+    /// The [Governor].
+    pub governor: Account<'info, Governor>,
+    /// The [OptimisticConfig].
+    #[account(
+        init,
+        seeds = [
+            b"TribecaOptimisticConfig".as_ref(),
+            governor.key().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + OptimisticConfig::LEN
+    )]
+    pub optimistic_config: Account<'info, OptimisticConfig>,
+    /// The Smart Wallet.
+    pub smart_wallet: Signer<'info>,
+    /// Payer of the [OptimisticConfig].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for [govern::set_optimistic_config].
+#[derive(Accounts)]
+pub struct SetOptimisticConfig<'info> {
+    /// The [Governor].
+    pub governor: Account<'info, Governor>,
+    /// The [OptimisticConfig].
+    #[account(mut)]
+    pub optimistic_config: Account<'info, OptimisticConfig>,
+    /// The Smart Wallet.
+    pub smart_wallet: Signer<'info>,
+}
+
+/// Accounts for [govern::set_parameter_override].
+#[derive(Accounts)]
+pub struct SetParameterOverride<'info> {
+    /// The [Governor].
+    pub governor: Account<'info, Governor>,
+    /// The [ParameterOverride].
+    #[account(mut)]
+    pub parameter_override: Account<'info, ParameterOverride>,
+    /// The Smart Wallet.
+    pub smart_wallet: Signer<'info>,
+}
+
+/// Accounts for [govern::apply_pending_parameter_override].
+#[derive(Accounts)]
+pub struct ApplyPendingParameterOverride<'info> {
+    /// The [ParameterOverride].
+    #[account(mut)]
+    pub parameter_override: Account<'info, ParameterOverride>,
+}
+
+/// Accounts for [govern::remove_parameter_override].
+#[derive(Accounts)]
+pub struct RemoveParameterOverride<'info> {
+    /// The [Governor].
+    pub governor: Account<'info, Governor>,
+    /// The [ParameterOverride].
+    #[account(mut, has_one = governor, close = payer)]
+    pub parameter_override: Account<'info, ParameterOverride>,
+    /// The Smart Wallet.
+    pub smart_wallet: Signer<'info>,
+    /// Receiver of the rent refund.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+/// Accounts for [govern::settle_proposal_deposit].
+#[derive(Accounts)]
+pub struct SettleProposalDeposit<'info> {
+    /// The [Governor].
+    pub governor: Account<'info, Governor>,
+    /// The [Proposal].
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+    /// The [Governor]'s deposit vault.
+    #[account(mut)]
+    pub deposit_vault: Account<'info, TokenAccount>,
+    /// The proposer's token account, which receives refunds.
+    #[account(mut)]
+    pub proposer_tokens: Account<'info, TokenAccount>,
+    /// The [Governor::smart_wallet]'s token account, which receives slashed deposits.
+    #[account(mut)]
+    pub treasury_tokens: Account<'info, TokenAccount>,
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+/// Accounts for [govern::close_proposal].
+#[derive(Accounts)]
+pub struct CloseProposal<'info> {
+    /// The [Proposal] to close.
+    #[account(mut, has_one = payer, close = payer)]
+    pub proposal: Account<'info, Proposal>,
+    /// The [Proposal::queued_transaction], or any account if the [Proposal] was not queued.
+    /// CHECK: Checked in [Validate] if the [Proposal] was queued.
+    pub transaction: UncheckedAccount<'info>,
+    /// Receives the rent of the [Proposal].
+    /// CHECK: Checked by `has_one`.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+/// Accounts for [govern::close_vote].
+#[derive(Accounts)]
+pub struct CloseVote<'info> {
+    /// The [Vote::proposal]. This may have already been closed.
+    /// CHECK: Checked in [Validate].
+    pub proposal: UncheckedAccount<'info>,
+    /// The [Proposal::queued_transaction], or any account if the [Proposal] was not queued.
+    /// CHECK: Checked in [Validate] if the [Proposal] was queued.
+    pub transaction: UncheckedAccount<'info>,
+    /// The [Vote] to close.
+    #[account(mut, has_one = proposal, has_one = payer, close = payer)]
+    pub vote: Account<'info, Vote>,
+    /// Receives the rent of the [Vote].
+    /// CHECK: Checked by `has_one`.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+/// Accounts for [govern::close_proposal_meta].
+#[derive(Accounts)]
+pub struct CloseProposalMeta<'info> {
+    /// The [ProposalMeta::proposal]. This may have already been closed.
+    /// CHECK: Checked in [Validate].
+    pub proposal: UncheckedAccount<'info>,
+    /// The [Proposal::queued_transaction], or any account if the [Proposal] was not queued.
+    /// CHECK: Checked in [Validate] if the [Proposal] was queued.
+    pub transaction: UncheckedAccount<'info>,
+    /// The [ProposalMeta] to close.
+    #[account(mut, has_one = proposal, close = payer)]
+    pub proposal_meta: Account<'info, ProposalMeta>,
+    /// Receives the rent of the [ProposalMeta].
+    /// CHECK: Checked in [Validate].
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+/// Accounts for [govern::create_vote_rationale].
+#[derive(Accounts)]
+pub struct CreateVoteRationale<'info> {
+    /// The [Vote].
+    pub vote: Account<'info, Vote>,
+    /// The [VoteRationale].
+    #[account(
+        init,
+        seeds = [
+            b"TribecaVoteRationale".as_ref(),
+            vote.key().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + VoteRationale::LEN
+    )]
+    pub vote_rationale: Account<'info, VoteRationale>,
+    /// Payer of the [VoteRationale].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for [govern::set_vote_rationale].
+#[derive(Accounts)]
+pub struct SetVoteRationale<'info> {
+    /// The [Governor].
+    pub governor: Account<'info, Governor>,
+    /// The [Proposal] voted on.
+    pub proposal: Account<'info, Proposal>,
+    /// The [Vote].
+    pub vote: Account<'info, Vote>,
+    /// The [VoteRationale].
+    #[account(mut)]
+    pub vote_rationale: Account<'info, VoteRationale>,
+    /// Either the [Vote::voter] or the [Governor::electorate].
+    pub authority: Signer<'info>,
+}
+
+/// Accounts for [govern::close_vote_rationale].
+#[derive(Accounts)]
+pub struct CloseVoteRationale<'info> {
+    /// The [Vote::proposal]. This may have already been closed.
+    /// CHECK: Checked in [Validate].
+    pub proposal: UncheckedAccount<'info>,
+    /// The [Proposal::queued_transaction], or any account if the [Proposal] was not queued.
+    /// CHECK: Checked in [Validate] if the [Proposal] was queued.
+    pub transaction: UncheckedAccount<'info>,
+    /// The [Vote] explained by the [VoteRationale].
+    #[account(has_one = proposal)]
+    pub vote: Account<'info, Vote>,
+    /// The [VoteRationale] to close.
+    #[account(mut, has_one = vote, has_one = payer, close = payer)]
+    pub vote_rationale: Account<'info, VoteRationale>,
+    /// Receives the rent of the [VoteRationale].
+    /// CHECK: Checked by `has_one`.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
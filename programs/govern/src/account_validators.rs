@@ -1,7 +1,8 @@
 //! Validates accounts structs.
 
 use crate::*;
-use vipers::{assert_keys_eq, invariant, unwrap_int, unwrap_opt, Validate};
+use anchor_lang::Discriminator;
+use vipers::{assert_keys_eq, invariant, program_err, unwrap_int, unwrap_opt, Validate};
 
 impl<'info> Validate<'info> for CreateGovernor<'info> {
     fn validate(&self) -> Result<()> {
@@ -20,6 +21,74 @@ impl<'info> Validate<'info> for CreateProposal<'info> {
     }
 }
 
+impl<'info> Validate<'info> for CreateStagedProposal<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for AppendProposalInstructions<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.proposer, self.proposal.proposer);
+        invariant!(!self.proposal.sealed, ProposalAlreadySealed);
+        invariant!(
+            self.proposal.kind == u8::from(ProposalKind::Standard) && !self.proposal.emergency,
+            InvalidProposalKind
+        );
+        invariant!(
+            self.proposal.get_state()? == ProposalState::Draft,
+            ProposalNotDraft
+        );
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for SealProposal<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.governor,
+            self.proposal.governor,
+            "proposal should be under the governor"
+        );
+        assert_keys_eq!(self.proposer, self.proposal.proposer);
+        invariant!(!self.proposal.sealed, ProposalAlreadySealed);
+        invariant!(
+            self.proposal.get_state()? == ProposalState::Draft,
+            ProposalNotDraft
+        );
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for CreateCommittedProposal<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for CreateOptimisticProposal<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.governor,
+            self.optimistic_config.governor,
+            "optimistic config should be under the governor"
+        );
+        invariant!(
+            self.optimistic_config
+                .proposers
+                .contains(&self.proposer.key()),
+            UnauthorizedOptimisticProposer
+        );
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for CreateMultipleChoiceProposal<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
 impl<'info> Validate<'info> for ActivateProposal<'info> {
     fn validate(&self) -> Result<()> {
         assert_keys_eq!(self.governor, self.proposal.governor);
@@ -28,12 +97,15 @@ impl<'info> Validate<'info> for ActivateProposal<'info> {
             self.proposal.get_state()? == ProposalState::Draft,
             ProposalNotDraft
         );
+        invariant!(self.proposal.sealed, ProposalNotSealed);
 
-        let earliest_activation_time = unwrap_int!(self
-            .governor
-            .params
-            .voting_delay
-            .checked_add(self.proposal.created_at as u64));
+        let voting_delay = if self.proposal.emergency {
+            self.proposal.emergency_voting_delay
+        } else {
+            self.governor.params.voting_delay
+        };
+        let earliest_activation_time =
+            unwrap_int!(voting_delay.checked_add(self.proposal.voting_delay_started_at() as u64));
         let now = Clock::get()?.unix_timestamp as u64;
         if earliest_activation_time > now {
             msg!(
@@ -48,6 +120,34 @@ impl<'info> Validate<'info> for ActivateProposal<'info> {
     }
 }
 
+impl<'info> Validate<'info> for CreateActiveProposalCounter<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for ReleaseActiveProposal<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.active_proposal_counter.governor,
+            self.proposal.governor,
+            InvalidActiveProposalCounter
+        );
+        assert_keys_eq!(
+            self.active_proposal_counter.proposer,
+            self.proposal.proposer,
+            InvalidActiveProposalCounter
+        );
+        invariant!(self.proposal.counted_as_active, "proposal is not counted");
+        let state = self.proposal.get_state()?;
+        invariant!(
+            state != ProposalState::Draft && state != ProposalState::Active,
+            ProposalStillActive
+        );
+        Ok(())
+    }
+}
+
 impl<'info> Validate<'info> for CancelProposal<'info> {
     fn validate(&self) -> Result<()> {
         assert_keys_eq!(
@@ -68,13 +168,37 @@ impl<'info> Validate<'info> for CancelProposal<'info> {
     }
 }
 
+impl<'info> Validate<'info> for VetoProposal<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(self.governor.guardian != Pubkey::default(), GuardianNotSet);
+        assert_keys_eq!(
+            self.guardian,
+            self.governor.guardian,
+            "guardian should match recorded"
+        );
+        assert_keys_eq!(
+            self.governor,
+            self.proposal.governor,
+            "proposal should be under the governor"
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        invariant!(
+            unwrap_opt!(self.proposal.is_vetoable(now), "invalid state"),
+            ProposalNotVetoable
+        );
+        Ok(())
+    }
+}
+
 impl<'info> Validate<'info> for QueueProposal<'info> {
     fn validate(&self) -> Result<()> {
         assert_keys_eq!(self.governor, self.proposal.governor);
         assert_keys_eq!(self.smart_wallet, self.governor.smart_wallet);
         let now = Clock::get()?.unix_timestamp;
         let proposal_state = unwrap_opt!(self.proposal.state(now), "invalid state");
-        if proposal_state != ProposalState::Succeeded {
+        invariant!(proposal_state != ProposalState::Expired, ProposalExpired);
+        if !unwrap_opt!(self.proposal.is_queueable(now), "invalid state") {
             msg!(
                 "now: {}, voting_ends_at: {}",
                 now,
@@ -86,14 +210,11 @@ impl<'info> Validate<'info> for QueueProposal<'info> {
                 self.proposal.against_votes,
             );
             msg!(
-                "quorum req: {}, abstain votes: {}",
-                self.governor.params.quorum_votes,
+                "quorum req: {:?}, abstain votes: {}",
+                self.proposal.quorum_votes_required(),
                 self.proposal.abstain_votes,
             );
-            invariant!(
-                proposal_state == ProposalState::Succeeded,
-                "proposal must be succeeded to be queued"
-            );
+            return program_err!(ProposalNotQueueable);
         }
         Ok(())
     }
@@ -122,6 +243,57 @@ impl<'info> Validate<'info> for SetVote<'info> {
             self.proposal.get_state()? == ProposalState::Active,
             ProposalNotActive
         );
+        invariant!(
+            !self.proposal.has_final_votes() || self.vote.side == u8::from(VoteSide::Pending),
+            VoteFinal
+        );
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for MigrateGovernor<'info> {
+    fn validate(&self) -> Result<()> {
+        let data = self.governor.try_borrow_data()?;
+        invariant!(
+            data.len() >= 8 && data[..8] == Governor::discriminator(),
+            "account is not a governor"
+        );
+        invariant!(
+            data.len() >= 8 + Governor::LEGACY_LEN,
+            "account is not a governor"
+        );
+        invariant!(data.len() < 8 + Governor::LEN, GovernorAlreadyMigrated);
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for MigrateVote<'info> {
+    fn validate(&self) -> Result<()> {
+        let data = self.vote.try_borrow_data()?;
+        invariant!(
+            data.len() >= 8 + Vote::LEGACY_LEN && data[..8] == Vote::discriminator(),
+            "account is not a vote"
+        );
+        invariant!(data.len() < 8 + Vote::LEN, VoteAlreadyMigrated);
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for MigrateProposal<'info> {
+    fn validate(&self) -> Result<()> {
+        let data = self.proposal.try_borrow_data()?;
+        invariant!(
+            data.len() >= 8 && data[..8] == Proposal::discriminator(),
+            "account is not a proposal"
+        );
+        let rest = &mut &data[8..];
+        let legacy = LegacyProposal::deserialize(rest)?;
+        // the version immediately follows the legacy layout and is zero until migrated
+        invariant!(
+            rest.first().copied().unwrap_or(0) == 0,
+            ProposalAlreadyMigrated
+        );
+        assert_keys_eq!(legacy.governor, self.governor);
         Ok(())
     }
 }
@@ -133,6 +305,50 @@ impl<'info> Validate<'info> for CreateProposalMeta<'info> {
     }
 }
 
+impl<'info> Validate<'info> for AmendProposalInstructions<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.governor,
+            self.proposal.governor,
+            "proposal should be under the governor"
+        );
+        assert_keys_eq!(self.proposer, self.proposal.proposer);
+        invariant!(
+            self.proposal.kind == u8::from(ProposalKind::Standard) && !self.proposal.emergency,
+            InvalidProposalKind
+        );
+        invariant!(!self.proposal.is_committed(), ProposalInstructionsCommitted);
+        invariant!(
+            self.proposal.get_state()? == ProposalState::Draft,
+            ProposalNotDraft
+        );
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for UpdateProposalMeta<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.proposer, self.proposal.proposer);
+        invariant!(
+            self.proposal.get_state()? == ProposalState::Draft,
+            ProposalNotDraft
+        );
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for ApplyPendingParams<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for ApplyPendingOptimisticConfig<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
 impl<'info> Validate<'info> for SetGovernanceParams<'info> {
     fn validate(&self) -> Result<()> {
         assert_keys_eq!(
@@ -143,3 +359,434 @@ impl<'info> Validate<'info> for SetGovernanceParams<'info> {
         Ok(())
     }
 }
+
+impl<'info> Validate<'info> for SetProposalDeposit<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.smart_wallet,
+            self.governor.smart_wallet,
+            "smart wallet should match"
+        );
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for CreateParameterOverride<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.smart_wallet,
+            self.governor.smart_wallet,
+            "smart wallet should match"
+        );
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for CreateOptimisticConfig<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.smart_wallet,
+            self.governor.smart_wallet,
+            "smart wallet should match"
+        );
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for SetOptimisticConfig<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.smart_wallet,
+            self.governor.smart_wallet,
+            "smart wallet should match"
+        );
+        assert_keys_eq!(
+            self.governor,
+            self.optimistic_config.governor,
+            "optimistic config should be under the governor"
+        );
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for SetParameterOverride<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.smart_wallet,
+            self.governor.smart_wallet,
+            "smart wallet should match"
+        );
+        assert_keys_eq!(
+            self.governor,
+            self.parameter_override.governor,
+            "parameter override should be under the governor"
+        );
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for ApplyPendingParameterOverride<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for RemoveParameterOverride<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.smart_wallet,
+            self.governor.smart_wallet,
+            "smart wallet should match"
+        );
+        assert_keys_eq!(
+            self.governor,
+            self.parameter_override.governor,
+            "parameter override should be under the governor"
+        );
+        Ok(())
+    }
+}
+
+/// Checks that a [Proposal] can no longer change.
+/// If the [Proposal] was queued, `transaction` must be its executed [Proposal::queued_transaction].
+fn assert_proposal_final(proposal: &Proposal, transaction: &AccountInfo) -> Result<()> {
+    let transaction_executed = if proposal.queued_at > 0 && proposal.vetoed_at == 0 {
+        assert_keys_eq!(transaction.key(), proposal.queued_transaction);
+        let transaction: Account<smart_wallet::Transaction> = Account::try_from(transaction)?;
+        transaction.executed_at >= 0
+    } else {
+        false
+    };
+    let now = Clock::get()?.unix_timestamp;
+    invariant!(
+        unwrap_opt!(
+            proposal.is_final(now, transaction_executed),
+            "invalid state"
+        ),
+        ProposalNotFinal
+    );
+    Ok(())
+}
+
+/// Checks that a [Proposal] which may have already been closed can no longer change.
+/// Closed proposals are always final, since only final proposals may be closed.
+fn assert_proposal_final_or_closed(
+    proposal: &AccountInfo,
+    transaction: &AccountInfo,
+) -> Result<()> {
+    if proposal.data_is_empty() {
+        return Ok(());
+    }
+    let proposal: Account<Proposal> = Account::try_from(proposal)?;
+    assert_proposal_final(&proposal, transaction)
+}
+
+impl<'info> Validate<'info> for CloseProposal<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(self.proposal.deposit_amount == 0, DepositNotSettled);
+        invariant!(!self.proposal.counted_as_active, ActiveProposalNotReleased);
+        assert_proposal_final(&self.proposal, &self.transaction)
+    }
+}
+
+impl<'info> Validate<'info> for CloseVote<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_proposal_final_or_closed(&self.proposal, &self.transaction)
+    }
+}
+
+impl<'info> Validate<'info> for CloseProposalMeta<'info> {
+    fn validate(&self) -> Result<()> {
+        if self.proposal_meta.payer == Pubkey::default() {
+            // legacy metas are refunded to the proposer, so the proposal must still exist
+            invariant!(!self.proposal.data_is_empty(), "proposal has been closed");
+            let proposal: Account<Proposal> = Account::try_from(&self.proposal)?;
+            assert_keys_eq!(self.payer, proposal.proposer);
+        } else {
+            assert_keys_eq!(self.payer, self.proposal_meta.payer);
+        }
+        assert_proposal_final_or_closed(&self.proposal, &self.transaction)
+    }
+}
+
+impl<'info> Validate<'info> for CreateVoteRationale<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for SetVoteRationale<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.governor,
+            self.proposal.governor,
+            "proposal should be under the governor"
+        );
+        assert_keys_eq!(
+            self.vote.proposal,
+            self.proposal,
+            "vote proposal should match"
+        );
+        assert_keys_eq!(self.vote_rationale.vote, self.vote);
+        invariant!(
+            self.authority.key() == self.vote.voter
+                || self.authority.key() == self.governor.electorate,
+            UnauthorizedVoteRationaleAuthority
+        );
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for CloseVoteRationale<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_proposal_final_or_closed(&self.proposal, &self.transaction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::{entrypoint::SUCCESS, program_stubs, system_program};
+
+    const NOW: i64 = 1_000;
+
+    struct TestSyscallStubs;
+
+    impl program_stubs::SyscallStubs for TestSyscallStubs {
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            let clock = Clock {
+                unix_timestamp: NOW,
+                ..Clock::default()
+            };
+            unsafe { *(var_addr as *mut Clock) = clock };
+            SUCCESS
+        }
+    }
+
+    fn set_clock() {
+        program_stubs::set_syscall_stubs(Box::new(TestSyscallStubs));
+    }
+
+    fn new_account_info(
+        key: Pubkey,
+        owner: Pubkey,
+        data: Vec<u8>,
+        is_signer: bool,
+        executable: bool,
+    ) -> AccountInfo<'static> {
+        AccountInfo::new(
+            Box::leak(Box::new(key)),
+            is_signer,
+            true,
+            Box::leak(Box::new(0)),
+            Box::leak(data.into_boxed_slice()),
+            Box::leak(Box::new(owner)),
+            executable,
+            0,
+        )
+    }
+
+    fn program_account_info<T: Discriminator + AnchorSerialize>(
+        account: &T,
+    ) -> AccountInfo<'static> {
+        let mut data = T::discriminator().to_vec();
+        data.extend(account.try_to_vec().unwrap());
+        new_account_info(Pubkey::new_unique(), crate::ID, data, false, false)
+    }
+
+    fn payer() -> Signer<'static> {
+        Signer::try_from(Box::leak(Box::new(new_account_info(
+            Pubkey::new_unique(),
+            system_program::ID,
+            vec![],
+            true,
+            false,
+        ))))
+        .unwrap()
+    }
+
+    fn system_program() -> Program<'static, System> {
+        let info = new_account_info(system_program::ID, Pubkey::default(), vec![], false, true);
+        Program::try_from(Box::leak(Box::new(info))).unwrap()
+    }
+
+    /// Emulates the reallocation done by the migration, which zero-fills the new space.
+    fn grow(info: &AccountInfo<'static>, new_len: usize) -> AccountInfo<'static> {
+        let mut data = info.try_borrow_data().unwrap().to_vec();
+        data.resize(new_len, 0);
+        new_account_info(*info.key, *info.owner, data, false, false)
+    }
+
+    #[test]
+    fn test_migrate_legacy_governor() {
+        let governor = Governor {
+            electorate: Pubkey::new_unique(),
+            smart_wallet: Pubkey::new_unique(),
+            params: GovernanceParameters {
+                voting_period: 500,
+                quorum_votes: 10,
+                timelock_delay_seconds: 60,
+                ..GovernanceParameters::default()
+            },
+            ..Governor::default()
+        };
+        let governor_info = program_account_info(&governor);
+        let legacy_info = grow(&governor_info, 8 + Governor::LEGACY_LEN);
+        let migrate = |info: &AccountInfo<'static>| MigrateGovernor {
+            governor: UncheckedAccount::try_from(info.clone()),
+            payer: payer(),
+            system_program: system_program(),
+        };
+        migrate(&legacy_info).validate().unwrap();
+
+        let migrated_info = grow(&legacy_info, 8 + Governor::LEN);
+        let migrated: Account<Governor> = Account::try_from(&migrated_info).unwrap();
+        assert_eq!(migrated.electorate, governor.electorate);
+        assert_eq!(migrated.smart_wallet, governor.smart_wallet);
+        assert_eq!(migrated.params.voting_period, 500);
+        assert_eq!(migrated.params.quorum_votes, 10);
+        assert_eq!(migrated.params.timelock_delay_seconds, 60);
+        assert_eq!(migrated.params.grace_period_seconds, 0);
+        assert!(migrate(&migrated_info).validate().is_err());
+
+        let vote_info = grow(
+            &program_account_info(&Vote::default()),
+            8 + Governor::LEGACY_LEN,
+        );
+        assert!(migrate(&vote_info).validate().is_err());
+    }
+
+    #[test]
+    fn test_migrate_legacy_proposal() {
+        let governor_info = program_account_info(&Governor {
+            params: GovernanceParameters {
+                voting_period: 500,
+                timelock_delay_seconds: 60,
+                grace_period_seconds: 600,
+                ..GovernanceParameters::default()
+            },
+            ..Governor::default()
+        });
+        let legacy = LegacyProposal {
+            governor: *governor_info.key,
+            proposer: Pubkey::new_unique(),
+            activated_at: 500,
+            voting_ends_at: 1_000,
+            instructions: vec![ProposalInstruction {
+                program_id: Pubkey::new_unique(),
+                keys: vec![],
+                data: vec![1, 2, 3],
+            }],
+            ..LegacyProposal::default()
+        };
+        let mut data = Proposal::discriminator().to_vec();
+        data.extend(legacy.try_to_vec().unwrap());
+        // legacy proposals were allocated with zeroed slack after their instructions
+        data.extend([0; 16]);
+        let legacy_info = new_account_info(Pubkey::new_unique(), crate::ID, data, false, false);
+        let migrate = |info: &AccountInfo<'static>| MigrateProposal {
+            governor: Account::try_from(&governor_info).unwrap(),
+            proposal: UncheckedAccount::try_from(info.clone()),
+            payer: payer(),
+            system_program: system_program(),
+        };
+        migrate(&legacy_info).validate().unwrap();
+
+        let other_governor_info = program_account_info(&Governor::default());
+        let mut wrong_governor = migrate(&legacy_info);
+        wrong_governor.governor = Account::try_from(&other_governor_info).unwrap();
+        assert!(wrong_governor.validate().is_err());
+
+        let governor: Account<Governor> = Account::try_from(&governor_info).unwrap();
+        let migrated_data = legacy.into_proposal(&governor.params).try_to_vec().unwrap();
+        let migrated_info = grow(&legacy_info, 8 + migrated_data.len());
+        migrated_info.try_borrow_mut_data().unwrap()[8..].copy_from_slice(&migrated_data);
+        let migrated: Account<Proposal> = Account::try_from(&migrated_info).unwrap();
+        assert_eq!(migrated.governor, *governor_info.key);
+        assert_eq!(migrated.payer, migrated.proposer);
+        assert_eq!(migrated.instructions.len(), 1);
+        assert_eq!(migrated.grace_period_seconds, 600);
+        assert!(migrate(&migrated_info).validate().is_err());
+    }
+
+    #[test]
+    fn test_migrate_legacy_vote() {
+        let vote = Vote {
+            proposal: Pubkey::new_unique(),
+            voter: Pubkey::new_unique(),
+            side: 1,
+            weight: 100,
+            ..Vote::default()
+        };
+        let legacy_info = grow(&program_account_info(&vote), 8 + Vote::LEGACY_LEN);
+        let migrate = |info: &AccountInfo<'static>| MigrateVote {
+            vote: UncheckedAccount::try_from(info.clone()),
+            payer: payer(),
+            system_program: system_program(),
+        };
+        migrate(&legacy_info).validate().unwrap();
+
+        let migrated_info = grow(&legacy_info, 8 + Vote::LEN);
+        let migrated: Account<Vote> = Account::try_from(&migrated_info).unwrap();
+        assert_eq!(migrated.proposal, vote.proposal);
+        assert_eq!(migrated.voter, vote.voter);
+        assert_eq!(migrated.side, 1);
+        assert_eq!(migrated.weight, 100);
+        assert_eq!(migrated.payer, Pubkey::default());
+        assert!(migrate(&migrated_info).validate().is_err());
+    }
+
+    #[test]
+    fn test_close_legacy_proposal_meta_refunds_proposer() {
+        set_clock();
+        let proposer = Pubkey::new_unique();
+        let proposal_info = program_account_info(&Proposal {
+            proposer,
+            canceled_at: 1,
+            ..Proposal::default()
+        });
+        let title = "Title".to_string();
+        let description_link = "https://example.com".to_string();
+        let mut data = ProposalMeta::discriminator().to_vec();
+        data.extend(
+            (*proposal_info.key, title.clone(), description_link.clone())
+                .try_to_vec()
+                .unwrap(),
+        );
+        // legacy metas were allocated without a payer
+        data.resize(8 + 80 + title.len() + description_link.len(), 0);
+        let proposal_meta_info =
+            new_account_info(Pubkey::new_unique(), crate::ID, data, false, false);
+        let close = |proposal: &AccountInfo<'static>, payer: Pubkey| CloseProposalMeta {
+            proposal: UncheckedAccount::try_from(proposal.clone()),
+            transaction: UncheckedAccount::try_from(new_account_info(
+                Pubkey::new_unique(),
+                system_program::ID,
+                vec![],
+                false,
+                false,
+            )),
+            proposal_meta: Account::try_from(&proposal_meta_info).unwrap(),
+            payer: UncheckedAccount::try_from(new_account_info(
+                payer,
+                system_program::ID,
+                vec![],
+                false,
+                false,
+            )),
+        };
+        assert_eq!(
+            close(&proposal_info, proposer).proposal_meta.payer,
+            Pubkey::default()
+        );
+
+        close(&proposal_info, proposer).validate().unwrap();
+        assert!(close(&proposal_info, Pubkey::new_unique())
+            .validate()
+            .is_err());
+
+        let closed_proposal_info =
+            new_account_info(*proposal_info.key, system_program::ID, vec![], false, false);
+        assert!(close(&closed_proposal_info, proposer).validate().is_err());
+    }
+}
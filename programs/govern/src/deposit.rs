@@ -0,0 +1,249 @@
+//! Proposal deposit logic.
+
+use crate::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address,
+    token::{self, TokenAccount},
+};
+use vipers::{assert_keys_eq, invariant, program_err, unwrap_opt};
+
+/// What should happen to the deposit of a [Proposal].
+#[derive(Debug, Eq, PartialEq)]
+pub enum DepositSettlement {
+    /// The deposit is returned to the proposer.
+    Refund,
+    /// The deposit is sent to the treasury of the [Governor].
+    Slash,
+}
+
+impl Proposal {
+    /// Determines what should happen to the deposit of this [Proposal], if anything.
+    ///
+    /// The deposit is refunded once voting ends if the proposal reached quorum, or if it was canceled as a draft.
+    /// It is slashed if the proposal is vetoed or defeated without reaching quorum.
+    /// Returns [None] if the deposit cannot be settled yet. Deposits of active proposals cannot be
+    /// settled, since votes may still be changed.
+    pub fn deposit_settlement(&self, current_time: i64) -> Result<Option<DepositSettlement>> {
+        let quorum_votes_required = unwrap_opt!(self.quorum_votes_required());
+        let meets_quorum = unwrap_opt!(self.meets_quorum(quorum_votes_required));
+        let settlement = match unwrap_opt!(self.state(current_time), "invalid state") {
+            ProposalState::Draft | ProposalState::Active => None,
+            ProposalState::Canceled => Some(DepositSettlement::Refund),
+            ProposalState::Vetoed => Some(DepositSettlement::Slash),
+            ProposalState::Defeated => {
+                if meets_quorum {
+                    Some(DepositSettlement::Refund)
+                } else {
+                    Some(DepositSettlement::Slash)
+                }
+            }
+            ProposalState::Succeeded | ProposalState::Queued | ProposalState::Expired => {
+                Some(DepositSettlement::Refund)
+            }
+        };
+        Ok(settlement)
+    }
+}
+
+/// Transfers the [Governor]'s proposal deposit from the proposer into the deposit vault, if one is required.
+///
+/// If a deposit is required, the first three remaining accounts must be the proposer's token account,
+/// the deposit vault (the [Governor]'s associated token account of [Governor::deposit_mint]), and the token program.
+/// Returns the remaining accounts that were not used.
+pub(crate) fn collect_proposal_deposit<'a, 'info>(
+    governor: &Account<'info, Governor>,
+    proposal: &mut Proposal,
+    proposer: &Signer<'info>,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<&'a [AccountInfo<'info>]> {
+    proposal.deposit_mint = governor.deposit_mint;
+    proposal.deposit_amount = governor.deposit_amount;
+    if governor.deposit_amount == 0 {
+        return Ok(remaining_accounts);
+    }
+
+    let (source_tokens, deposit_vault, token_program, rest) = match remaining_accounts {
+        [source_tokens, deposit_vault, token_program, rest @ ..] => {
+            (source_tokens, deposit_vault, token_program, rest)
+        }
+        _ => return program_err!(ProposalDepositRequired),
+    };
+    assert_keys_eq!(token_program.key(), token::ID);
+    assert_keys_eq!(
+        deposit_vault.key(),
+        get_associated_token_address(&governor.key(), &governor.deposit_mint),
+        InvalidDepositVault
+    );
+
+    token::transfer(
+        CpiContext::new(
+            token_program.clone(),
+            token::Transfer {
+                from: source_tokens.clone(),
+                to: deposit_vault.clone(),
+                authority: proposer.to_account_info(),
+            },
+        ),
+        governor.deposit_amount,
+    )?;
+
+    Ok(rest)
+}
+
+/// Checks that the `deposit_vault` is an initialized token account of the `deposit_mint`
+/// at the [Governor]'s associated token address.
+pub(crate) fn check_deposit_vault(
+    governor: &Account<Governor>,
+    deposit_vault: &AccountInfo,
+    deposit_mint: Pubkey,
+) -> Result<()> {
+    assert_keys_eq!(
+        deposit_vault.key(),
+        get_associated_token_address(&governor.key(), &deposit_mint),
+        InvalidDepositVault
+    );
+    let vault = Account::<TokenAccount>::try_from(deposit_vault)?;
+    assert_keys_eq!(vault.mint, deposit_mint, InvalidDepositVault);
+    assert_keys_eq!(vault.owner, governor.key(), InvalidDepositVault);
+    Ok(())
+}
+
+impl<'info> SettleProposalDeposit<'info> {
+    /// Refunds or slashes the deposit of the [Proposal].
+    pub fn settle(&mut self) -> Result<DepositSettlement> {
+        let now = Clock::get()?.unix_timestamp;
+        let settlement = unwrap_opt!(self.proposal.deposit_settlement(now)?, DepositNotSettleable);
+        let destination = match settlement {
+            DepositSettlement::Refund => &self.proposer_tokens,
+            DepositSettlement::Slash => &self.treasury_tokens,
+        };
+
+        let seeds = governor_seeds!(self.governor);
+        let signer_seeds = &[&seeds[..]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                token::Transfer {
+                    from: self.deposit_vault.to_account_info(),
+                    to: destination.to_account_info(),
+                    authority: self.governor.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            self.proposal.deposit_amount,
+        )?;
+
+        self.proposal.deposit_amount = 0;
+
+        Ok(settlement)
+    }
+}
+
+impl<'info> Validate<'info> for SettleProposalDeposit<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.governor,
+            self.proposal.governor,
+            "proposal should be under the governor"
+        );
+        invariant!(self.proposal.deposit_amount > 0, DepositNotSettleable);
+        assert_keys_eq!(
+            self.deposit_vault,
+            get_associated_token_address(&self.governor.key(), &self.proposal.deposit_mint),
+            InvalidDepositVault
+        );
+        assert_keys_eq!(self.proposer_tokens.owner, self.proposal.proposer);
+        assert_keys_eq!(self.proposer_tokens.mint, self.proposal.deposit_mint);
+        assert_keys_eq!(self.treasury_tokens.owner, self.governor.smart_wallet);
+        assert_keys_eq!(self.treasury_tokens.mint, self.proposal.deposit_mint);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn proposal_with_votes(for_votes: u64, quorum_votes: u64) -> Proposal {
+        Proposal {
+            activated_at: 1,
+            voting_ends_at: 100,
+            for_votes,
+            quorum_votes,
+            deposit_amount: 1_000,
+            ..Proposal::default()
+        }
+    }
+
+    #[test]
+    fn test_draft_not_settleable() {
+        let proposal = Proposal::default();
+        assert_eq!(proposal.deposit_settlement(1).unwrap(), None);
+    }
+
+    #[test]
+    fn test_canceled_refunded() {
+        let proposal = Proposal {
+            canceled_at: 1,
+            ..Proposal::default()
+        };
+        assert_eq!(
+            proposal.deposit_settlement(1).unwrap(),
+            Some(DepositSettlement::Refund)
+        );
+    }
+
+    #[test]
+    fn test_vetoed_slashed() {
+        let proposal = Proposal {
+            vetoed_at: 50,
+            ..proposal_with_votes(200, 100)
+        };
+        assert_eq!(
+            proposal.deposit_settlement(50).unwrap(),
+            Some(DepositSettlement::Slash)
+        );
+    }
+
+    #[test]
+    fn test_active_not_settleable() {
+        assert_eq!(
+            proposal_with_votes(99, 100).deposit_settlement(50).unwrap(),
+            None
+        );
+        assert_eq!(
+            proposal_with_votes(100, 100)
+                .deposit_settlement(50)
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_defeated() {
+        assert_eq!(
+            proposal_with_votes(99, 100)
+                .deposit_settlement(100)
+                .unwrap(),
+            Some(DepositSettlement::Slash)
+        );
+        let proposal = Proposal {
+            against_votes: 200,
+            ..proposal_with_votes(100, 100)
+        };
+        assert_eq!(
+            proposal.deposit_settlement(100).unwrap(),
+            Some(DepositSettlement::Refund)
+        );
+    }
+
+    #[test]
+    fn test_succeeded_refunded() {
+        assert_eq!(
+            proposal_with_votes(100, 100)
+                .deposit_settlement(100)
+                .unwrap(),
+            Some(DepositSettlement::Refund)
+        );
+    }
+}
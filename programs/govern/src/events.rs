@@ -31,6 +31,113 @@ pub struct ProposalCreateEvent {
     pub instructions: Vec<ProposalInstruction>,
 }
 
+/// Event called in [govern::create_committed_proposal].
+#[event]
+pub struct CommittedProposalCreateEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal being created.
+    #[index]
+    pub proposal: Pubkey,
+    /// The index of the [Proposal].
+    pub index: u64,
+    /// The commitment to the instructions of the proposal.
+    pub instructions_hash: [u8; 32],
+}
+
+/// Event called in [govern::create_optimistic_proposal].
+#[event]
+pub struct OptimisticProposalCreateEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal being created.
+    #[index]
+    pub proposal: Pubkey,
+    /// The index of the [Proposal].
+    pub index: u64,
+    /// The number of against votes above which the proposal is defeated.
+    pub veto_votes: u64,
+    /// The instructions associated with the proposal.
+    pub instructions: Vec<ProposalInstruction>,
+}
+
+/// Event called in [govern::create_emergency_proposal].
+#[event]
+pub struct EmergencyProposalCreateEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal being created.
+    #[index]
+    pub proposal: Pubkey,
+    /// The index of the [Proposal].
+    pub index: u64,
+    /// The emergency parameters snapshotted onto the proposal.
+    pub params: EmergencyParameters,
+    /// The instructions associated with the proposal.
+    pub instructions: Vec<ProposalInstruction>,
+}
+
+/// Event called in [govern::create_multiple_choice_proposal].
+#[event]
+pub struct MultipleChoiceProposalCreateEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal being created.
+    #[index]
+    pub proposal: Pubkey,
+    /// The index of the [Proposal].
+    pub index: u64,
+    /// Instructions of each option of the proposal.
+    pub options: Vec<Vec<ProposalInstruction>>,
+}
+
+/// Event called in [govern::append_proposal_instructions].
+#[event]
+pub struct ProposalInstructionsAppendEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal.
+    #[index]
+    pub proposal: Pubkey,
+    /// The instructions appended.
+    pub instructions: Vec<ProposalInstruction>,
+}
+
+/// Event called in [govern::seal_proposal].
+#[event]
+pub struct ProposalSealEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal.
+    #[index]
+    pub proposal: Pubkey,
+    /// SHA-256 hash of the serialized instructions of the proposal.
+    pub instructions_hash: [u8; 32],
+    /// Number of instructions of the proposal.
+    pub instruction_count: u64,
+}
+
+/// Event called in [govern::amend_proposal_instructions].
+#[event]
+pub struct ProposalInstructionsAmendEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal being amended.
+    #[index]
+    pub proposal: Pubkey,
+    /// SHA-256 hash of the serialized instructions of the proposal after the amendment.
+    pub instructions_hash: [u8; 32],
+    /// Number of instructions of the proposal after the amendment.
+    pub instruction_count: u64,
+}
+
 /// Event called in [govern::cancel_proposal].
 #[event]
 pub struct ProposalActivateEvent {
@@ -55,6 +162,19 @@ pub struct ProposalCancelEvent {
     pub proposal: Pubkey,
 }
 
+/// Event called in [govern::veto_proposal].
+#[event]
+pub struct ProposalVetoEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal being vetoed.
+    #[index]
+    pub proposal: Pubkey,
+    /// The guardian that vetoed the proposal.
+    pub guardian: Pubkey,
+}
+
 /// Event called in [govern::queue_proposal].
 #[event]
 pub struct ProposalQueueEvent {
@@ -69,6 +189,22 @@ pub struct ProposalQueueEvent {
     pub transaction: Pubkey,
 }
 
+/// Event called in [govern::queue_proposal] when queueing an emergency proposal.
+#[event]
+pub struct EmergencyProposalQueueEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal being queued.
+    #[index]
+    pub proposal: Pubkey,
+    /// The transaction key.
+    #[index]
+    pub transaction: Pubkey,
+    /// True if the proposal was queued before its voting period ended.
+    pub queued_early: bool,
+}
+
 /// Event called in [govern::set_vote].
 #[event]
 pub struct VoteSetEvent {
@@ -91,6 +227,21 @@ pub struct VoteSetEvent {
     pub weight: u64,
 }
 
+/// Event called in [govern::set_vote] when a late vote extends voting.
+#[event]
+pub struct ProposalVotingExtendEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal being voted on.
+    #[index]
+    pub proposal: Pubkey,
+    /// When voting was previously scheduled to end.
+    pub prev_voting_ends_at: i64,
+    /// When voting now ends.
+    pub voting_ends_at: i64,
+}
+
 /// Event called in [govern::create_proposal_meta].
 #[event]
 pub struct ProposalMetaCreateEvent {
@@ -106,8 +257,52 @@ pub struct ProposalMetaCreateEvent {
     pub description_link: String,
 }
 
+/// Event called in [govern::update_proposal_meta].
+#[event]
+pub struct ProposalMetaUpdateEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal.
+    #[index]
+    pub proposal: Pubkey,
+    /// The previous title.
+    pub prev_title: String,
+    /// The previous description.
+    pub prev_description_link: String,
+    /// The new title.
+    pub title: String,
+    /// The new description.
+    pub description_link: String,
+}
+
 /// Event called in [govern::set_governance_params].
 #[event]
+pub struct GovernorStageParamsEvent {
+    /// The governor being updated.
+    #[index]
+    pub governor: Pubkey,
+    /// Staged [GovernanceParameters].
+    pub params: GovernanceParameters,
+    /// When the [GovernanceParameters] take effect.
+    pub effective_at: i64,
+}
+
+/// Event called in [govern::set_electorate].
+#[event]
+pub struct GovernorStageElectorateEvent {
+    /// The governor being updated.
+    #[index]
+    pub governor: Pubkey,
+    /// Staged [Governor::electorate].
+    pub new_electorate: Pubkey,
+    /// When the electorate takes effect.
+    pub effective_at: i64,
+}
+
+/// Event called when staged [GovernanceParameters] are applied,
+/// in [govern::apply_pending_params] or [govern::set_governance_params].
+#[event]
 pub struct GovernorSetParamsEvent {
     /// The governor being created.
     #[index]
@@ -118,7 +313,8 @@ pub struct GovernorSetParamsEvent {
     pub params: GovernanceParameters,
 }
 
-/// Event called in [govern::set_electorate].
+/// Event called when a staged electorate is applied,
+/// in [govern::apply_pending_params] or [govern::set_electorate].
 #[event]
 pub struct GovernorSetElectorateEvent {
     /// The governor being created.
@@ -129,3 +325,306 @@ pub struct GovernorSetElectorateEvent {
     /// New [Governor::electorate].
     pub new_electorate: Pubkey,
 }
+
+/// Event called in [govern::set_proposal_creation_gate].
+#[event]
+pub struct GovernorStageProposalCreationGateEvent {
+    /// The governor being updated.
+    #[index]
+    pub governor: Pubkey,
+    /// Staged [Governor::proposal_creation_gate].
+    pub new_gate: Pubkey,
+    /// When the gate takes effect.
+    pub effective_at: i64,
+}
+
+/// Event called when a staged [Governor::proposal_creation_gate] is applied,
+/// in [govern::apply_pending_params] or [govern::set_proposal_creation_gate].
+#[event]
+pub struct GovernorSetProposalCreationGateEvent {
+    /// The governor being updated.
+    #[index]
+    pub governor: Pubkey,
+    /// Previous [Governor::proposal_creation_gate].
+    pub prev_gate: Pubkey,
+    /// New [Governor::proposal_creation_gate].
+    pub new_gate: Pubkey,
+}
+
+/// Event called in [govern::set_guardian].
+#[event]
+pub struct GovernorStageGuardianEvent {
+    /// The governor being updated.
+    #[index]
+    pub governor: Pubkey,
+    /// Staged [Governor::guardian].
+    pub new_guardian: Pubkey,
+    /// When the guardian takes effect.
+    pub effective_at: i64,
+}
+
+/// Event called when a staged [Governor::guardian] is applied,
+/// in [govern::apply_pending_params] or [govern::set_guardian].
+#[event]
+pub struct GovernorSetGuardianEvent {
+    /// The governor being updated.
+    #[index]
+    pub governor: Pubkey,
+    /// Previous [Governor::guardian].
+    pub prev_guardian: Pubkey,
+    /// New [Governor::guardian].
+    pub new_guardian: Pubkey,
+}
+
+/// Event called in [govern::set_emergency_params].
+#[event]
+pub struct GovernorStageEmergencyParamsEvent {
+    /// The governor being updated.
+    #[index]
+    pub governor: Pubkey,
+    /// Staged [EmergencyParameters].
+    pub params: EmergencyParameters,
+    /// When the [EmergencyParameters] take effect.
+    pub effective_at: i64,
+}
+
+/// Event called when staged [EmergencyParameters] are applied,
+/// in [govern::apply_pending_params] or [govern::set_emergency_params].
+#[event]
+pub struct GovernorSetEmergencyParamsEvent {
+    /// The governor being updated.
+    #[index]
+    pub governor: Pubkey,
+    /// Previous [Governor::emergency_params].
+    pub prev_params: EmergencyParameters,
+    /// New [Governor::emergency_params].
+    pub params: EmergencyParameters,
+}
+
+/// Event called in [govern::create_parameter_override].
+#[event]
+pub struct ParameterOverrideCreateEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The program whose invocations the parameters apply to.
+    #[index]
+    pub program_id: Pubkey,
+}
+
+/// Event called in [govern::create_parameter_override] and [govern::set_parameter_override].
+#[event]
+pub struct ParameterOverrideStageEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The program whose invocations the parameters apply to.
+    #[index]
+    pub program_id: Pubkey,
+    /// Staged [OverrideParameters].
+    pub params: OverrideParameters,
+    /// When the parameters take effect.
+    pub effective_at: i64,
+}
+
+/// Event called in [govern::set_optimistic_config].
+#[event]
+pub struct OptimisticConfigStageEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The staged parameters.
+    pub params: OptimisticParameters,
+    /// The staged proposers.
+    pub proposers: Vec<Pubkey>,
+    /// When the parameters and proposers take effect.
+    pub effective_at: i64,
+}
+
+/// Event called in [govern::create_optimistic_config], and when staged changes are applied,
+/// in [govern::apply_pending_optimistic_config] or [govern::set_optimistic_config].
+#[event]
+pub struct OptimisticConfigSetEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The new parameters.
+    pub params: OptimisticParameters,
+    /// The new proposers.
+    pub proposers: Vec<Pubkey>,
+}
+
+/// Event called when staged [OverrideParameters] are applied, in [govern::apply_pending_parameter_override],
+/// [govern::create_parameter_override], [govern::set_parameter_override] or [govern::remove_parameter_override].
+#[event]
+pub struct ParameterOverrideSetEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The program whose invocations the parameters apply to.
+    #[index]
+    pub program_id: Pubkey,
+    /// Previous [OverrideParameters].
+    pub prev_params: OverrideParameters,
+    /// New [OverrideParameters].
+    pub params: OverrideParameters,
+}
+
+/// Event called in [govern::remove_parameter_override].
+#[event]
+pub struct ParameterOverrideRemoveEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The program whose invocations the parameters applied to.
+    #[index]
+    pub program_id: Pubkey,
+}
+
+/// Event called in [govern::set_proposal_deposit].
+#[event]
+pub struct GovernorStageProposalDepositEvent {
+    /// The governor being updated.
+    #[index]
+    pub governor: Pubkey,
+    /// Staged [Governor::deposit_mint].
+    pub deposit_mint: Pubkey,
+    /// Staged [Governor::deposit_amount].
+    pub deposit_amount: u64,
+    /// When the deposit takes effect.
+    pub effective_at: i64,
+}
+
+/// Event called when a staged proposal deposit is applied,
+/// in [govern::apply_pending_params] or [govern::set_proposal_deposit].
+#[event]
+pub struct GovernorSetProposalDepositEvent {
+    /// The governor being updated.
+    #[index]
+    pub governor: Pubkey,
+    /// Previous [Governor::deposit_mint].
+    pub prev_deposit_mint: Pubkey,
+    /// Previous [Governor::deposit_amount].
+    pub prev_deposit_amount: u64,
+    /// New [Governor::deposit_mint].
+    pub deposit_mint: Pubkey,
+    /// New [Governor::deposit_amount].
+    pub deposit_amount: u64,
+}
+
+/// Event called in [govern::settle_proposal_deposit].
+#[event]
+pub struct ProposalDepositSettleEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal whose deposit was settled.
+    #[index]
+    pub proposal: Pubkey,
+    /// The number of tokens refunded or slashed.
+    pub amount: u64,
+    /// True if the deposit was sent to the treasury rather than refunded.
+    pub slashed: bool,
+}
+
+/// Event called in [govern::set_vote_rationale].
+#[event]
+pub struct VoteRationaleSetEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal voted on.
+    #[index]
+    pub proposal: Pubkey,
+    /// The voter.
+    #[index]
+    pub voter: Pubkey,
+    /// The [Vote].
+    pub vote: Pubkey,
+    /// Hash of the rationale content.
+    pub content_hash: [u8; 32],
+    /// URI of the rationale content.
+    pub uri: String,
+}
+
+/// Event called in [govern::release_active_proposal].
+#[event]
+pub struct ActiveProposalReleaseEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal being released.
+    #[index]
+    pub proposal: Pubkey,
+    /// The proposer.
+    #[index]
+    pub proposer: Pubkey,
+    /// The proposer's remaining number of active proposals.
+    pub active_proposals: u64,
+}
+
+/// Event called in [govern::close_proposal].
+#[event]
+pub struct ProposalCloseEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal being closed.
+    #[index]
+    pub proposal: Pubkey,
+    /// The account which received the rent.
+    pub payer: Pubkey,
+}
+
+/// Event called in [govern::close_vote].
+#[event]
+pub struct VoteCloseEvent {
+    /// The proposal voted on.
+    #[index]
+    pub proposal: Pubkey,
+    /// The voter.
+    #[index]
+    pub voter: Pubkey,
+    /// The account which received the rent.
+    pub payer: Pubkey,
+}
+
+/// Event called in [govern::close_proposal_meta].
+#[event]
+pub struct ProposalMetaCloseEvent {
+    /// The proposal.
+    #[index]
+    pub proposal: Pubkey,
+    /// The account which received the rent.
+    pub payer: Pubkey,
+}
+
+/// Event called in [govern::migrate_governor].
+#[event]
+pub struct GovernorMigrateEvent {
+    /// The governor being migrated.
+    #[index]
+    pub governor: Pubkey,
+}
+
+/// Event called in [govern::migrate_vote].
+#[event]
+pub struct VoteMigrateEvent {
+    /// The proposal being voted on.
+    #[index]
+    pub proposal: Pubkey,
+    /// The voter.
+    #[index]
+    pub voter: Pubkey,
+}
+
+/// Event called in [govern::migrate_proposal].
+#[event]
+pub struct ProposalMigrateEvent {
+    /// The governor of the proposal.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal being migrated.
+    #[index]
+    pub proposal: Pubkey,
+}
@@ -9,13 +9,17 @@ use vipers::prelude::*;
 
 mod account_structs;
 mod account_validators;
+pub mod deposit;
 mod events;
 mod macros;
+mod pending;
 pub mod proposal;
+pub mod realloc;
 mod state;
 
 use account_structs::*;
 
+pub use deposit::*;
 pub use events::*;
 pub use proposal::*;
 pub use state::*;
@@ -35,10 +39,7 @@ pub mod govern {
         electorate: Pubkey,
         params: GovernanceParameters,
     ) -> Result<()> {
-        invariant!(
-            params.timelock_delay_seconds >= 0,
-            "timelock delay must be at least 0 seconds"
-        );
+        params.validate()?;
 
         let governor = &mut ctx.accounts.governor;
         governor.base = ctx.accounts.base.key();
@@ -62,32 +63,82 @@ pub mod govern {
 
     /// Creates a [Proposal].
     /// This may be called by anyone, since the [Proposal] does not do anything until
-    /// it is activated in [activate_proposal].
+    /// it is activated in [activate_proposal], unless the [Governor] has a
+    /// [Governor::proposal_creation_gate]. In that case, the gate must be the first
+    /// remaining account and must sign, typically via CPI from the electorate.
+    ///
+    /// If the [Governor] requires a proposal deposit, the next remaining accounts must be
+    /// the proposer's token account, the deposit vault (the [Governor]'s associated token account
+    /// of [Governor::deposit_mint]), and the token program.
+    /// The rest of the remaining accounts must be the [ParameterOverride] address of each
+    /// program invoked by the instructions, in order of first invocation.
     #[access_control(ctx.accounts.validate())]
-    pub fn create_proposal(
-        ctx: Context<CreateProposal>,
+    pub fn create_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateProposal<'info>>,
         _bump: u8,
         instructions: Vec<ProposalInstruction>,
     ) -> Result<()> {
         let governor = &mut ctx.accounts.governor;
 
         let proposal = &mut ctx.accounts.proposal;
-        proposal.governor = governor.key();
-        proposal.index = governor.proposal_count;
-        proposal.bump = unwrap_bump!(ctx, "proposal");
+        proposal.init(
+            governor,
+            ctx.accounts.proposer.key(),
+            unwrap_bump!(ctx, "proposal"),
+        )?;
+        proposal.payer = ctx.accounts.payer.key();
+        let override_accounts = deposit::collect_proposal_deposit(
+            governor,
+            proposal,
+            &ctx.accounts.proposer,
+            governor.check_proposal_creation_gate(ctx.remaining_accounts)?,
+        )?;
+        proposal.apply_parameter_overrides(
+            instructions.iter().map(|ix| ix.program_id),
+            override_accounts,
+        )?;
+        proposal.instructions = instructions.clone();
 
-        proposal.proposer = ctx.accounts.proposer.key();
+        governor.proposal_count += 1;
 
-        proposal.quorum_votes = governor.params.quorum_votes;
-        proposal.created_at = Clock::get()?.unix_timestamp;
-        proposal.canceled_at = 0;
-        proposal.activated_at = 0;
-        proposal.voting_ends_at = 0;
+        emit!(ProposalCreateEvent {
+            governor: governor.key(),
+            proposal: proposal.key(),
+            index: proposal.index,
+            instructions,
+        });
 
-        proposal.queued_at = 0;
-        proposal.queued_transaction = Pubkey::default();
+        Ok(())
+    }
 
-        proposal.instructions = instructions.clone();
+    /// Creates an empty, unsealed [Proposal] with room for `capacity` bytes of instructions.
+    ///
+    /// This allows proposals with more instructions than fit in a single transaction.
+    /// Instructions are added with [append_proposal_instructions], after which the
+    /// proposal must be sealed with [seal_proposal] before it can be activated.
+    /// The remaining accounts must be the proposal creation gate and deposit accounts, if required.
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_staged_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateStagedProposal<'info>>,
+        _bump: u8,
+        _capacity: u32,
+    ) -> Result<()> {
+        let governor = &mut ctx.accounts.governor;
+
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.init(
+            governor,
+            ctx.accounts.proposer.key(),
+            unwrap_bump!(ctx, "proposal"),
+        )?;
+        proposal.payer = ctx.accounts.payer.key();
+        proposal.sealed = false;
+        deposit::collect_proposal_deposit(
+            governor,
+            proposal,
+            &ctx.accounts.proposer,
+            governor.check_proposal_creation_gate(ctx.remaining_accounts)?,
+        )?;
 
         governor.proposal_count += 1;
 
@@ -95,32 +146,392 @@ pub mod govern {
             governor: governor.key(),
             proposal: proposal.key(),
             index: proposal.index,
+            instructions: vec![],
+        });
+
+        Ok(())
+    }
+
+    /// Appends instructions to an unsealed [Proposal].
+    /// This may only be called by the proposer.
+    #[access_control(ctx.accounts.validate())]
+    pub fn append_proposal_instructions(
+        ctx: Context<AppendProposalInstructions>,
+        instructions: Vec<ProposalInstruction>,
+    ) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let mut new_instructions = proposal.instructions.clone();
+        new_instructions.extend(instructions.iter().cloned());
+        invariant!(
+            Proposal::space(new_instructions.clone()) <= proposal.to_account_info().data_len(),
+            ProposalCapacityExceeded
+        );
+        proposal.instructions = new_instructions;
+
+        emit!(ProposalInstructionsAppendEvent {
+            governor: proposal.governor,
+            proposal: proposal.key(),
+            instructions,
+        });
+
+        Ok(())
+    }
+
+    /// Seals a [Proposal], allowing it to be activated. No more instructions may be appended.
+    /// This may only be called by the proposer.
+    ///
+    /// The parameters of the [Proposal] are snapshotted again, so the remaining accounts must be
+    /// the [ParameterOverride] address of each program invoked by the instructions, as in [create_proposal].
+    /// The voting delay starts once the proposal is sealed.
+    #[access_control(ctx.accounts.validate())]
+    pub fn seal_proposal(ctx: Context<SealProposal>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.snapshot_parameters(&ctx.accounts.governor.params);
+        let instructions = proposal.instructions.clone();
+        proposal.apply_parameter_overrides(
+            instructions.iter().map(|ix| ix.program_id),
+            ctx.remaining_accounts,
+        )?;
+        proposal.amended_at = Clock::get()?.unix_timestamp;
+        proposal.sealed = true;
+
+        emit!(ProposalSealEvent {
+            governor: proposal.governor,
+            proposal: proposal.key(),
+            instructions_hash: hash_instructions(&instructions)?,
+            instruction_count: instructions.len() as u64,
+        });
+
+        Ok(())
+    }
+
+    /// Creates a [Proposal] which only stores a commitment to its instructions.
+    ///
+    /// This is useful for proposals whose instructions are too costly to store on-chain.
+    /// The instructions are revealed when the proposal is queued via [queue_committed_proposal].
+    /// A description of the proposal should be published via [create_proposal_meta].
+    ///
+    /// Since the instructions are not known, the proposer declares the programs they invoke.
+    /// The remaining accounts must be the proposal creation gate and deposit accounts, if required, followed by the
+    /// [ParameterOverride] address of each of the `invoked_program_ids`.
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_committed_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateCommittedProposal<'info>>,
+        _bump: u8,
+        instructions_hash: [u8; 32],
+        invoked_program_ids: Vec<Pubkey>,
+    ) -> Result<()> {
+        invariant!(instructions_hash != [0; 32], InvalidInstructionsHash);
+        let governor = &mut ctx.accounts.governor;
+
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.init(
+            governor,
+            ctx.accounts.proposer.key(),
+            unwrap_bump!(ctx, "proposal"),
+        )?;
+        proposal.payer = ctx.accounts.payer.key();
+        proposal.instructions_hash = instructions_hash;
+        let override_accounts = deposit::collect_proposal_deposit(
+            governor,
+            proposal,
+            &ctx.accounts.proposer,
+            governor.check_proposal_creation_gate(ctx.remaining_accounts)?,
+        )?;
+        proposal.apply_parameter_overrides(invoked_program_ids.into_iter(), override_accounts)?;
+
+        governor.proposal_count += 1;
+
+        emit!(CommittedProposalCreateEvent {
+            governor: governor.key(),
+            proposal: proposal.key(),
+            index: proposal.index,
+            instructions_hash,
+        });
+
+        Ok(())
+    }
+
+    /// Creates an optimistic [Proposal], which succeeds once voting ends unless
+    /// more than [OptimisticParameters::veto_votes] are cast against it.
+    ///
+    /// This may only be called by one of the [OptimisticConfig::proposers], so the
+    /// [Governor::proposal_creation_gate] does not apply: the remaining accounts are
+    /// those of [create_proposal] without the gate.
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_optimistic_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateOptimisticProposal<'info>>,
+        _bump: u8,
+        instructions: Vec<ProposalInstruction>,
+    ) -> Result<()> {
+        let governor = &mut ctx.accounts.governor;
+        let params = ctx.accounts.optimistic_config.params;
+
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.init(
+            governor,
+            ctx.accounts.proposer.key(),
+            unwrap_bump!(ctx, "proposal"),
+        )?;
+        proposal.payer = ctx.accounts.payer.key();
+        proposal.kind = ProposalKind::Optimistic.into();
+        if params.voting_period > 0 {
+            proposal.voting_period = params.voting_period;
+        }
+        let override_accounts = deposit::collect_proposal_deposit(
+            governor,
+            proposal,
+            &ctx.accounts.proposer,
+            ctx.remaining_accounts,
+        )?;
+        proposal.apply_parameter_overrides(
+            instructions.iter().map(|ix| ix.program_id),
+            override_accounts,
+        )?;
+        // quorum and approval threshold do not apply to optimistic proposals
+        proposal.quorum_votes = 0;
+        proposal.quorum_bps = 0;
+        proposal.approval_threshold_bps = 0;
+        proposal.veto_votes = params.veto_votes;
+        proposal.instructions = instructions.clone();
+
+        governor.proposal_count += 1;
+
+        emit!(OptimisticProposalCreateEvent {
+            governor: governor.key(),
+            proposal: proposal.key(),
+            index: proposal.index,
+            veto_votes: params.veto_votes,
+            instructions,
+        });
+
+        Ok(())
+    }
+
+    /// Creates an emergency [Proposal], which uses the [Governor::emergency_params].
+    ///
+    /// Emergency proposals have shorter periods but require a higher quorum and approval threshold,
+    /// and may be queued as soon as they pass. The remaining accounts are the same as in [create_proposal],
+    /// since [ParameterOverride]s still apply.
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_emergency_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateProposal<'info>>,
+        _bump: u8,
+        instructions: Vec<ProposalInstruction>,
+    ) -> Result<()> {
+        let governor = &mut ctx.accounts.governor;
+        let params = governor.emergency_params;
+        invariant!(params.is_enabled(), EmergencyProposalsDisabled);
+        // the governor's parameters may have become stricter since the emergency parameters were set
+        params.validate(&governor.params)?;
+
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.init(
+            governor,
+            ctx.accounts.proposer.key(),
+            unwrap_bump!(ctx, "proposal"),
+        )?;
+        proposal.payer = ctx.accounts.payer.key();
+        proposal.snapshot_emergency_parameters(&params);
+        let override_accounts = deposit::collect_proposal_deposit(
+            governor,
+            proposal,
+            &ctx.accounts.proposer,
+            governor.check_proposal_creation_gate(ctx.remaining_accounts)?,
+        )?;
+        proposal.apply_parameter_overrides(
+            instructions.iter().map(|ix| ix.program_id),
+            override_accounts,
+        )?;
+        proposal.instructions = instructions.clone();
+
+        governor.proposal_count += 1;
+
+        emit!(EmergencyProposalCreateEvent {
+            governor: governor.key(),
+            proposal: proposal.key(),
+            index: proposal.index,
+            params,
             instructions,
         });
 
         Ok(())
     }
 
+    /// Creates a multiple-choice [Proposal].
+    ///
+    /// Each option has its own set of instructions, which may be empty for signalling votes.
+    /// Only the instructions of the winning option are queued into the [SmartWallet].
+    /// Like [create_proposal], this may be called by anyone, and the remaining accounts must be
+    /// the proposal creation gate and deposit accounts, if required, followed by the [ParameterOverride] address
+    /// of each program invoked by any option.
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_multiple_choice_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateMultipleChoiceProposal<'info>>,
+        _bump: u8,
+        options: Vec<Vec<ProposalInstruction>>,
+    ) -> Result<()> {
+        invariant!(
+            (2..=MAX_PROPOSAL_OPTIONS).contains(&options.len()),
+            InvalidProposalOptionCount
+        );
+
+        let governor = &mut ctx.accounts.governor;
+
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.init(
+            governor,
+            ctx.accounts.proposer.key(),
+            unwrap_bump!(ctx, "proposal"),
+        )?;
+        proposal.payer = ctx.accounts.payer.key();
+        proposal.kind = ProposalKind::MultipleChoice.into();
+        let override_accounts = deposit::collect_proposal_deposit(
+            governor,
+            proposal,
+            &ctx.accounts.proposer,
+            governor.check_proposal_creation_gate(ctx.remaining_accounts)?,
+        )?;
+        proposal.apply_parameter_overrides(
+            options.iter().flatten().map(|ix| ix.program_id),
+            override_accounts,
+        )?;
+        proposal.options = options
+            .iter()
+            .map(|instructions| ProposalOption {
+                votes: 0,
+                instructions: instructions.clone(),
+            })
+            .collect();
+
+        governor.proposal_count += 1;
+
+        emit!(MultipleChoiceProposalCreateEvent {
+            governor: governor.key(),
+            proposal: proposal.key(),
+            index: proposal.index,
+            options,
+        });
+
+        Ok(())
+    }
+
+    /// Amends the instructions of a [Proposal].
+    /// This may only be called by the proposer while the [Proposal] is a [ProposalState::Draft].
+    ///
+    /// If `append` is true, the instructions are appended to the existing instructions;
+    /// otherwise, they replace them. The voting delay restarts, and the parameters of the
+    /// [Proposal] are snapshotted again, so the remaining accounts must be the [ParameterOverride]
+    /// address of each program invoked by the resulting instructions, as in [create_proposal].
+    #[access_control(ctx.accounts.validate())]
+    pub fn amend_proposal_instructions(
+        ctx: Context<AmendProposalInstructions>,
+        instructions: Vec<ProposalInstruction>,
+        append: bool,
+    ) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let mut new_instructions = if append {
+            proposal.instructions.clone()
+        } else {
+            vec![]
+        };
+        new_instructions.extend(instructions);
+
+        realloc::grow_account(
+            &proposal.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            Proposal::space(new_instructions.clone()),
+        )?;
+
+        proposal.snapshot_parameters(&ctx.accounts.governor.params);
+        proposal.apply_parameter_overrides(
+            new_instructions.iter().map(|ix| ix.program_id),
+            ctx.remaining_accounts,
+        )?;
+        proposal.amended_at = Clock::get()?.unix_timestamp;
+
+        let instructions_hash = hash_instructions(&new_instructions)?;
+        let instruction_count = new_instructions.len() as u64;
+        proposal.instructions = new_instructions;
+
+        emit!(ProposalInstructionsAmendEvent {
+            governor: proposal.governor,
+            proposal: proposal.key(),
+            instructions_hash,
+            instruction_count,
+        });
+
+        Ok(())
+    }
+
     /// Activates a proposal.
     /// Only the [Governor::electorate] may call this; that program
     /// may ensure that only certain types of users can activate proposals.
+    ///
+    /// If [GovernanceParameters::max_active_proposals_per_proposer] is set,
+    /// the first remaining account must be the proposer's [ActiveProposalCounter].
+    #[access_control(ctx.accounts.validate())]
+    pub fn activate_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ActivateProposal<'info>>,
+    ) -> Result<()> {
+        invariant!(
+            ctx.accounts.proposal.quorum_bps == 0,
+            TotalVotingPowerRequired
+        );
+        ctx.accounts.activate(ctx.remaining_accounts, 0)
+    }
+
+    /// Activates a proposal, recording the total voting power of the electorate.
+    /// This is required for proposals with a quorum in basis points of the total voting power.
+    /// Only the [Governor::electorate] may call this.
+    ///
+    /// Remaining accounts are the same as in [activate_proposal].
     #[access_control(ctx.accounts.validate())]
-    pub fn activate_proposal(ctx: Context<ActivateProposal>) -> Result<()> {
+    pub fn activate_proposal_with_voting_power<'info>(
+        ctx: Context<'_, '_, '_, 'info, ActivateProposal<'info>>,
+        total_voting_power: u64,
+    ) -> Result<()> {
+        invariant!(
+            total_voting_power > 0 || ctx.accounts.proposal.quorum_bps == 0,
+            TotalVotingPowerRequired
+        );
+        ctx.accounts
+            .activate(ctx.remaining_accounts, total_voting_power)
+    }
+
+    /// Creates the [ActiveProposalCounter] of a proposer. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_active_proposal_counter(
+        ctx: Context<CreateActiveProposalCounter>,
+        _bump: u8,
+        proposer: Pubkey,
+    ) -> Result<()> {
+        let counter = &mut ctx.accounts.active_proposal_counter;
+        counter.governor = ctx.accounts.governor.key();
+        counter.proposer = proposer;
+        counter.bump = unwrap_bump!(ctx, "active_proposal_counter");
+        counter.active_proposals = 0;
+
+        Ok(())
+    }
+
+    /// Releases a [Proposal] which is no longer active from its proposer's
+    /// [ActiveProposalCounter], allowing the proposer to activate another proposal.
+    /// Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn release_active_proposal(ctx: Context<ReleaseActiveProposal>) -> Result<()> {
+        let counter = &mut ctx.accounts.active_proposal_counter;
+        counter.decrement()?;
+
         let proposal = &mut ctx.accounts.proposal;
-        let now = Clock::get()?.unix_timestamp;
-        proposal.activated_at = now;
-        proposal.voting_ends_at = unwrap_int!(ctx
-            .accounts
-            .governor
-            .params
-            .voting_period
-            .to_i64()
-            .and_then(|v: i64| now.checked_add(v)));
-
-        emit!(ProposalActivateEvent {
+        proposal.counted_as_active = false;
+
+        emit!(ActiveProposalReleaseEvent {
             governor: proposal.governor,
             proposal: proposal.key(),
-            voting_ends_at: proposal.voting_ends_at,
+            proposer: proposal.proposer,
+            active_proposals: counter.active_proposals,
         });
 
         Ok(())
@@ -141,10 +552,91 @@ pub mod govern {
         Ok(())
     }
 
+    /// Vetoes a proposal.
+    /// This is only callable by the [Governor::guardian], and only before the proposal is executed.
+    /// If the proposal was already queued, the [Governor]'s approval of the
+    /// queued transaction is revoked, so the first remaining account must be the
+    /// [Proposal::queued_transaction]. Otherwise, no remaining accounts are required.
+    #[access_control(ctx.accounts.validate())]
+    pub fn veto_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, VetoProposal<'info>>,
+    ) -> Result<()> {
+        if ctx.accounts.proposal.queued_at > 0 {
+            let transaction = unwrap_opt!(
+                ctx.remaining_accounts.first(),
+                "queued transaction must be provided"
+            );
+            ctx.accounts.unapprove_transaction(transaction)?;
+        }
+
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.vetoed_at = Clock::get()?.unix_timestamp;
+
+        emit!(ProposalVetoEvent {
+            governor: proposal.governor,
+            proposal: proposal.key(),
+            guardian: ctx.accounts.guardian.key(),
+        });
+
+        Ok(())
+    }
+
     /// Queues a proposal for execution by the [SmartWallet].
     #[access_control(ctx.accounts.validate())]
     pub fn queue_proposal(ctx: Context<QueueProposal>, tx_bump: u8) -> Result<()> {
-        ctx.accounts.queue_transaction(tx_bump)?;
+        invariant!(
+            !ctx.accounts.proposal.is_committed(),
+            ProposalInstructionsCommitted
+        );
+        let voting_ends_at = ctx.accounts.proposal.voting_ends_at;
+        let instructions = ctx.accounts.proposal.to_smart_wallet_instructions();
+        ctx.accounts.queue_transaction(tx_bump, instructions)?;
+
+        if ctx.accounts.proposal.emergency {
+            emit!(EmergencyProposalQueueEvent {
+                governor: ctx.accounts.proposal.governor,
+                proposal: ctx.accounts.proposal.key(),
+                transaction: ctx.accounts.transaction.key(),
+                queued_early: ctx.accounts.proposal.voting_ends_at < voting_ends_at,
+            });
+        } else {
+            emit!(ProposalQueueEvent {
+                governor: ctx.accounts.proposal.governor,
+                proposal: ctx.accounts.proposal.key(),
+                transaction: ctx.accounts.transaction.key(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Queues a proposal created via [create_committed_proposal] for execution by the [SmartWallet],
+    /// revealing its instructions.
+    ///
+    /// The instructions must match [Proposal::instructions_hash]. The remaining accounts must be the
+    /// [ParameterOverride] address of each program invoked by the instructions, in order of first invocation,
+    /// and the parameters of the proposal must be at least as strict as those overrides.
+    #[access_control(ctx.accounts.validate())]
+    pub fn queue_committed_proposal(
+        ctx: Context<QueueProposal>,
+        tx_bump: u8,
+        instructions: Vec<ProposalInstruction>,
+    ) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        invariant!(proposal.is_committed(), ProposalInstructionsNotCommitted);
+        invariant!(
+            hash_instructions(&instructions)? == proposal.instructions_hash,
+            InvalidInstructionsHash
+        );
+        invariant!(
+            proposal.meets_parameter_overrides(
+                instructions.iter().map(|ix| ix.program_id),
+                ctx.remaining_accounts
+            )?,
+            InvalidParameterOverrides
+        );
+        ctx.accounts
+            .queue_transaction(tx_bump, to_smart_wallet_instructions(&instructions))?;
 
         emit!(ProposalQueueEvent {
             governor: ctx.accounts.proposal.governor,
@@ -165,19 +657,40 @@ pub mod govern {
 
         vote.side = VoteSide::Pending.into();
         vote.weight = 0;
+        vote.payer = ctx.accounts.payer.key();
 
         Ok(())
     }
 
     /// Sets a [Vote] weight and side.
     /// This may only be called by the [Governor::electorate].
+    ///
+    /// On a multiple-choice [Proposal], the `side` is the index of the chosen option plus one,
+    /// with zero meaning [VoteSide::Pending].
+    ///
+    /// Votes on proposals which may be queued early are final once cast; see [Proposal::has_final_votes].
     #[access_control(ctx.accounts.validate())]
     pub fn set_vote(ctx: Context<SetVote>, side: u8, weight: u64) -> Result<()> {
         let vote = &ctx.accounts.vote;
 
         let proposal = &mut ctx.accounts.proposal;
-        proposal.subtract_vote_weight(vote.side.try_into()?, vote.weight)?;
-        proposal.add_vote_weight(side.try_into()?, weight)?;
+        let prev_outcome = unwrap_opt!(proposal.outcome(), "invalid outcome");
+        proposal.subtract_vote(vote.side, vote.weight)?;
+        proposal.add_vote(side, weight)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let extension = unwrap_int!(proposal.vote_extension(now, prev_outcome));
+        if extension > 0 {
+            let prev_voting_ends_at = proposal.voting_ends_at;
+            proposal.extend_voting(extension)?;
+
+            emit!(ProposalVotingExtendEvent {
+                governor: proposal.governor,
+                proposal: proposal.key(),
+                prev_voting_ends_at,
+                voting_ends_at: proposal.voting_ends_at,
+            });
+        }
 
         let vote = &mut ctx.accounts.vote;
         vote.side = side;
@@ -195,35 +708,463 @@ pub mod govern {
         Ok(())
     }
 
-    /// Sets the [GovernanceParameters].
+    /// Stages new [GovernanceParameters], replacing any pending parameters.
+    /// They may be applied with [apply_pending_params] once
+    /// [GovernanceParameters::params_change_delay_seconds] have passed,
+    /// or are applied immediately if there is no delay.
     /// This may only be called by the [Governor::smart_wallet].
     #[access_control(ctx.accounts.validate())]
     pub fn set_governance_params(
         ctx: Context<SetGovernanceParams>,
         params: GovernanceParameters,
     ) -> Result<()> {
-        let prev_params = ctx.accounts.governor.params;
-        ctx.accounts.governor.params = params;
+        params.validate()?;
 
-        emit!(GovernorSetParamsEvent {
-            governor: ctx.accounts.governor.key(),
-            prev_params,
+        let now = Clock::get()?.unix_timestamp;
+        let governor = &mut ctx.accounts.governor;
+        let effective_at = governor.pending_change_effective_at(now)?;
+        governor.pending_params = params;
+        governor.pending_params_effective_at = effective_at;
+
+        emit!(GovernorStageParamsEvent {
+            governor: governor.key(),
             params,
+            effective_at,
         });
 
+        pending::apply_pending_changes(governor, now);
+
         Ok(())
     }
 
-    /// Sets the electorate of the [Governor].
+    /// Stages a new electorate of the [Governor], replacing any pending electorate.
+    /// It may be applied with [apply_pending_params] once
+    /// [GovernanceParameters::params_change_delay_seconds] have passed,
+    /// or is applied immediately if there is no delay.
+    /// This may only be called by the [Governor::smart_wallet].
     #[access_control(ctx.accounts.validate())]
     pub fn set_electorate(ctx: Context<SetGovernanceParams>, new_electorate: Pubkey) -> Result<()> {
-        let prev_electorate = ctx.accounts.governor.electorate;
-        ctx.accounts.governor.electorate = new_electorate;
+        let now = Clock::get()?.unix_timestamp;
+        let governor = &mut ctx.accounts.governor;
+        let effective_at = governor.pending_change_effective_at(now)?;
+        governor.pending_electorate = new_electorate;
+        governor.pending_electorate_effective_at = effective_at;
 
-        emit!(GovernorSetElectorateEvent {
-            governor: ctx.accounts.governor.key(),
-            prev_electorate,
+        emit!(GovernorStageElectorateEvent {
+            governor: governor.key(),
             new_electorate,
+            effective_at,
+        });
+
+        pending::apply_pending_changes(governor, now);
+
+        Ok(())
+    }
+
+    /// Applies the pending changes of the [Governor] which have taken effect,
+    /// i.e. its [GovernanceParameters], electorate, [EmergencyParameters],
+    /// [Governor::proposal_creation_gate], proposal deposit and guardian. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn apply_pending_params(ctx: Context<ApplyPendingParams>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        invariant!(
+            pending::apply_pending_changes(&mut ctx.accounts.governor, now),
+            NoPendingChangesInEffect
+        );
+        Ok(())
+    }
+
+    /// Creates a [ParameterOverride] for proposals invoking the given program,
+    /// staging its [OverrideParameters]. They may be applied with [apply_pending_parameter_override]
+    /// once [GovernanceParameters::params_change_delay_seconds] have passed,
+    /// or are applied immediately if there is no delay.
+    /// This may only be called by the [Governor::smart_wallet].
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_parameter_override(
+        ctx: Context<CreateParameterOverride>,
+        _bump: u8,
+        target_program_id: Pubkey,
+        params: OverrideParameters,
+    ) -> Result<()> {
+        params.validate(&ctx.accounts.governor.params)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let effective_at = ctx.accounts.governor.pending_change_effective_at(now)?;
+        let parameter_override = &mut ctx.accounts.parameter_override;
+        parameter_override.governor = ctx.accounts.governor.key();
+        parameter_override.program_id = target_program_id;
+        parameter_override.bump = unwrap_bump!(ctx, "parameter_override");
+        parameter_override.pending_params = params;
+        parameter_override.pending_effective_at = effective_at;
+
+        emit!(ParameterOverrideCreateEvent {
+            governor: parameter_override.governor,
+            program_id: target_program_id,
+        });
+        emit!(ParameterOverrideStageEvent {
+            governor: parameter_override.governor,
+            program_id: target_program_id,
+            params,
+            effective_at,
+        });
+
+        pending::apply_pending_parameter_override(parameter_override, now);
+
+        Ok(())
+    }
+
+    /// Creates the [OptimisticConfig] of a [Governor], enabling optimistic proposals.
+    /// This may only be called by the [Governor::smart_wallet].
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_optimistic_config(
+        ctx: Context<CreateOptimisticConfig>,
+        _bump: u8,
+        params: OptimisticParameters,
+        proposers: Vec<Pubkey>,
+    ) -> Result<()> {
+        invariant!(
+            proposers.len() <= MAX_OPTIMISTIC_PROPOSERS,
+            TooManyOptimisticProposers
+        );
+
+        let optimistic_config = &mut ctx.accounts.optimistic_config;
+        optimistic_config.governor = ctx.accounts.governor.key();
+        optimistic_config.bump = unwrap_bump!(ctx, "optimistic_config");
+        optimistic_config.params = params;
+        optimistic_config.proposers = proposers.clone();
+
+        emit!(OptimisticConfigSetEvent {
+            governor: optimistic_config.governor,
+            params,
+            proposers,
+        });
+
+        Ok(())
+    }
+
+    /// Stages new [OptimisticParameters] and proposers of an [OptimisticConfig],
+    /// replacing any pending changes. They may be applied with [apply_pending_optimistic_config]
+    /// once [GovernanceParameters::params_change_delay_seconds] have passed,
+    /// or are applied immediately if there is no delay.
+    /// Setting no proposers disables optimistic proposals.
+    /// This may only be called by the [Governor::smart_wallet].
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_optimistic_config(
+        ctx: Context<SetOptimisticConfig>,
+        params: OptimisticParameters,
+        proposers: Vec<Pubkey>,
+    ) -> Result<()> {
+        invariant!(
+            proposers.len() <= MAX_OPTIMISTIC_PROPOSERS,
+            TooManyOptimisticProposers
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let effective_at = ctx.accounts.governor.pending_change_effective_at(now)?;
+        let optimistic_config = &mut ctx.accounts.optimistic_config;
+        optimistic_config.pending_params = params;
+        optimistic_config.pending_proposers = proposers.clone();
+        optimistic_config.pending_effective_at = effective_at;
+
+        emit!(OptimisticConfigStageEvent {
+            governor: optimistic_config.governor,
+            params,
+            proposers,
+            effective_at,
+        });
+
+        pending::apply_pending_optimistic_config(optimistic_config, now);
+
+        Ok(())
+    }
+
+    /// Applies the pending [OptimisticParameters] and proposers of an [OptimisticConfig]
+    /// if they have taken effect. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn apply_pending_optimistic_config(
+        ctx: Context<ApplyPendingOptimisticConfig>,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        invariant!(
+            pending::apply_pending_optimistic_config(&mut ctx.accounts.optimistic_config, now),
+            NoPendingChangesInEffect
+        );
+        Ok(())
+    }
+
+    /// Stages new [OverrideParameters] of a [ParameterOverride], replacing any pending parameters.
+    /// They may be applied with [apply_pending_parameter_override] once
+    /// [GovernanceParameters::params_change_delay_seconds] have passed,
+    /// or are applied immediately if there is no delay.
+    /// This may only be called by the [Governor::smart_wallet].
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_parameter_override(
+        ctx: Context<SetParameterOverride>,
+        params: OverrideParameters,
+    ) -> Result<()> {
+        params.validate(&ctx.accounts.governor.params)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let effective_at = ctx.accounts.governor.pending_change_effective_at(now)?;
+        let parameter_override = &mut ctx.accounts.parameter_override;
+        parameter_override.pending_params = params;
+        parameter_override.pending_effective_at = effective_at;
+
+        emit!(ParameterOverrideStageEvent {
+            governor: parameter_override.governor,
+            program_id: parameter_override.program_id,
+            params,
+            effective_at,
+        });
+
+        pending::apply_pending_parameter_override(parameter_override, now);
+
+        Ok(())
+    }
+
+    /// Applies the pending [OverrideParameters] of a [ParameterOverride]
+    /// if they have taken effect. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn apply_pending_parameter_override(
+        ctx: Context<ApplyPendingParameterOverride>,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        invariant!(
+            pending::apply_pending_parameter_override(&mut ctx.accounts.parameter_override, now),
+            NoPendingChangesInEffect
+        );
+        Ok(())
+    }
+
+    /// Removes a [ParameterOverride], refunding its rent to the payer.
+    /// This may only be called by the [Governor::smart_wallet].
+    ///
+    /// Only a [ParameterOverride] without effect may be removed, so that removing it is also
+    /// subject to the delay: its parameters must first be set to the defaults via [set_parameter_override].
+    #[access_control(ctx.accounts.validate())]
+    pub fn remove_parameter_override(ctx: Context<RemoveParameterOverride>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        pending::apply_pending_parameter_override(&mut ctx.accounts.parameter_override, now);
+        invariant!(
+            ctx.accounts.parameter_override.is_cleared(),
+            ParameterOverrideNotCleared
+        );
+
+        emit!(ParameterOverrideRemoveEvent {
+            governor: ctx.accounts.parameter_override.governor,
+            program_id: ctx.accounts.parameter_override.program_id,
+        });
+
+        Ok(())
+    }
+
+    /// Stages a new proposal deposit of the [Governor], replacing any pending deposit.
+    /// It may be applied with [apply_pending_params] once
+    /// [GovernanceParameters::params_change_delay_seconds] have passed,
+    /// or is applied immediately if there is no delay.
+    /// Setting the amount to zero disables proposal deposits.
+    /// Otherwise, the deposit vault must already exist.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_proposal_deposit(
+        ctx: Context<SetProposalDeposit>,
+        deposit_mint: Pubkey,
+        deposit_amount: u64,
+    ) -> Result<()> {
+        if deposit_amount > 0 {
+            deposit::check_deposit_vault(
+                &ctx.accounts.governor,
+                &ctx.accounts.deposit_vault,
+                deposit_mint,
+            )?;
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+        let governor = &mut ctx.accounts.governor;
+        let effective_at = governor.pending_change_effective_at(now)?;
+        governor.pending_deposit_mint = deposit_mint;
+        governor.pending_deposit_amount = deposit_amount;
+        governor.pending_deposit_effective_at = effective_at;
+
+        emit!(GovernorStageProposalDepositEvent {
+            governor: governor.key(),
+            deposit_mint,
+            deposit_amount,
+            effective_at,
+        });
+
+        pending::apply_pending_changes(governor, now);
+
+        Ok(())
+    }
+
+    /// Refunds or slashes the deposit of a [Proposal]. Anyone can call this.
+    /// See [Proposal::deposit_settlement] for when this is possible.
+    #[access_control(ctx.accounts.validate())]
+    pub fn settle_proposal_deposit(ctx: Context<SettleProposalDeposit>) -> Result<()> {
+        let amount = ctx.accounts.proposal.deposit_amount;
+        let settlement = ctx.accounts.settle()?;
+
+        emit!(ProposalDepositSettleEvent {
+            governor: ctx.accounts.governor.key(),
+            proposal: ctx.accounts.proposal.key(),
+            amount,
+            slashed: settlement == DepositSettlement::Slash,
+        });
+
+        Ok(())
+    }
+
+    /// Stages new [EmergencyParameters] of the [Governor], replacing any pending parameters.
+    /// They may be applied with [apply_pending_params] once
+    /// [GovernanceParameters::params_change_delay_seconds] have passed,
+    /// or are applied immediately if there is no delay.
+    /// Setting the approval threshold to zero disables emergency proposals.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_emergency_params(
+        ctx: Context<SetGovernanceParams>,
+        params: EmergencyParameters,
+    ) -> Result<()> {
+        params.validate(&ctx.accounts.governor.params)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let governor = &mut ctx.accounts.governor;
+        let effective_at = governor.pending_change_effective_at(now)?;
+        governor.pending_emergency_params = params;
+        governor.pending_emergency_params_effective_at = effective_at;
+
+        emit!(GovernorStageEmergencyParamsEvent {
+            governor: governor.key(),
+            params,
+            effective_at,
+        });
+
+        pending::apply_pending_changes(governor, now);
+
+        Ok(())
+    }
+
+    /// Stages a new [Governor::proposal_creation_gate], replacing any pending gate.
+    /// It may be applied with [apply_pending_params] once
+    /// [GovernanceParameters::params_change_delay_seconds] have passed,
+    /// or is applied immediately if there is no delay.
+    /// Setting the gate to [Pubkey::default] allows anyone to create proposals.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_proposal_creation_gate(
+        ctx: Context<SetGovernanceParams>,
+        new_gate: Pubkey,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let governor = &mut ctx.accounts.governor;
+        let effective_at = governor.pending_change_effective_at(now)?;
+        governor.pending_proposal_creation_gate = new_gate;
+        governor.pending_proposal_creation_gate_effective_at = effective_at;
+
+        emit!(GovernorStageProposalCreationGateEvent {
+            governor: governor.key(),
+            new_gate,
+            effective_at,
+        });
+
+        pending::apply_pending_changes(governor, now);
+
+        Ok(())
+    }
+
+    /// Stages a new guardian of the [Governor], replacing any pending guardian.
+    /// It may be applied with [apply_pending_params] once
+    /// [GovernanceParameters::params_change_delay_seconds] have passed,
+    /// or is applied immediately if there is no delay.
+    /// Setting the guardian to [Pubkey::default] removes the guardian.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_guardian(ctx: Context<SetGovernanceParams>, new_guardian: Pubkey) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let governor = &mut ctx.accounts.governor;
+        let effective_at = governor.pending_change_effective_at(now)?;
+        governor.pending_guardian = new_guardian;
+        governor.pending_guardian_effective_at = effective_at;
+
+        emit!(GovernorStageGuardianEvent {
+            governor: governor.key(),
+            new_guardian,
+            effective_at,
+        });
+
+        pending::apply_pending_changes(governor, now);
+
+        Ok(())
+    }
+
+    /// Migrates a [Governor] created before [GovernanceParameters::grace_period_seconds] existed
+    /// to the current [Governor] layout.
+    ///
+    /// The new space is zero-filled, so the migrated [Governor]'s proposals never expire.
+    /// Anyone may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_governor(ctx: Context<MigrateGovernor>) -> Result<()> {
+        realloc::grow_account(
+            &ctx.accounts.governor.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            8 + Governor::LEN,
+        )?;
+
+        emit!(GovernorMigrateEvent {
+            governor: ctx.accounts.governor.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Migrates a [Proposal] created before [Proposal::version] existed to the current [Proposal] layout.
+    ///
+    /// The migrated [Proposal] behaves as it did before: it is a sealed, standard proposal
+    /// whose voting period, timelock delay and grace period are those of the [Governor].
+    /// The rent is refunded to the proposer once closed. Anyone may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_proposal(ctx: Context<MigrateProposal>) -> Result<()> {
+        let proposal_info = ctx.accounts.proposal.to_account_info();
+        let legacy = LegacyProposal::deserialize(&mut &proposal_info.try_borrow_data()?[8..])?;
+        let data = legacy
+            .into_proposal(&ctx.accounts.governor.params)
+            .try_to_vec()?;
+
+        realloc::grow_account(
+            &proposal_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            8 + data.len(),
+        )?;
+        proposal_info.try_borrow_mut_data()?[8..8 + data.len()].copy_from_slice(&data);
+
+        emit!(ProposalMigrateEvent {
+            governor: ctx.accounts.governor.key(),
+            proposal: proposal_info.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Migrates a [Vote] created before [Vote::payer] existed to the current [Vote] layout.
+    /// Legacy votes cannot be changed until they are migrated.
+    ///
+    /// The [Vote::payer] is set to the voter. Anyone may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_vote(ctx: Context<MigrateVote>) -> Result<()> {
+        let vote_info = ctx.accounts.vote.to_account_info();
+        realloc::grow_account(
+            &vote_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            8 + Vote::LEN,
+        )?;
+
+        let mut vote = Account::<Vote>::try_from(&vote_info)?;
+        vote.payer = vote.voter;
+        vote.exit(&crate::ID)?;
+
+        emit!(VoteMigrateEvent {
+            proposal: vote.proposal,
+            voter: vote.voter,
         });
 
         Ok(())
@@ -239,6 +1180,7 @@ pub mod govern {
     ) -> Result<()> {
         let proposal_meta = &mut ctx.accounts.proposal_meta;
         proposal_meta.proposal = ctx.accounts.proposal.key();
+        proposal_meta.payer = ctx.accounts.payer.key();
         proposal_meta.title = title.clone();
         proposal_meta.description_link = description_link.clone();
 
@@ -251,6 +1193,126 @@ pub mod govern {
 
         Ok(())
     }
+
+    /// Updates a [ProposalMeta].
+    /// This may only be called by the proposer while the [Proposal] is a [ProposalState::Draft].
+    /// The account is grown if needed, with the additional rent paid by the `payer`.
+    #[access_control(ctx.accounts.validate())]
+    pub fn update_proposal_meta(
+        ctx: Context<UpdateProposalMeta>,
+        title: String,
+        description_link: String,
+    ) -> Result<()> {
+        realloc::grow_account(
+            &ctx.accounts.proposal_meta.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ProposalMeta::space(&title, &description_link),
+        )?;
+
+        let proposal_meta = &mut ctx.accounts.proposal_meta;
+        let prev_title = std::mem::replace(&mut proposal_meta.title, title.clone());
+        let prev_description_link = std::mem::replace(
+            &mut proposal_meta.description_link,
+            description_link.clone(),
+        );
+
+        emit!(ProposalMetaUpdateEvent {
+            governor: ctx.accounts.proposal.governor,
+            proposal: ctx.accounts.proposal.key(),
+            prev_title,
+            prev_description_link,
+            title,
+            description_link,
+        });
+
+        Ok(())
+    }
+
+    /// Creates an empty [VoteRationale] for a [Vote]. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_vote_rationale(ctx: Context<CreateVoteRationale>, _bump: u8) -> Result<()> {
+        let vote_rationale = &mut ctx.accounts.vote_rationale;
+        vote_rationale.vote = ctx.accounts.vote.key();
+        vote_rationale.bump = unwrap_bump!(ctx, "vote_rationale");
+        vote_rationale.payer = ctx.accounts.payer.key();
+        Ok(())
+    }
+
+    /// Sets the rationale of a [Vote].
+    /// This may only be called by the [Vote::voter] or by the [Governor::electorate],
+    /// which allows electorates to let vote delegates publish rationales.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_vote_rationale(
+        ctx: Context<SetVoteRationale>,
+        content_hash: [u8; 32],
+        uri: String,
+    ) -> Result<()> {
+        invariant!(
+            uri.len() <= MAX_VOTE_RATIONALE_URI_LEN,
+            VoteRationaleTooLong
+        );
+        let vote_rationale = &mut ctx.accounts.vote_rationale;
+        vote_rationale.content_hash = content_hash;
+        vote_rationale.uri = uri.clone();
+
+        emit!(VoteRationaleSetEvent {
+            governor: ctx.accounts.governor.key(),
+            proposal: ctx.accounts.proposal.key(),
+            voter: ctx.accounts.vote.voter,
+            vote: ctx.accounts.vote.key(),
+            content_hash,
+            uri,
+        });
+
+        Ok(())
+    }
+
+    /// Closes a [Proposal] which can no longer change, refunding rent to its [Proposal::payer].
+    /// Anyone can call this. The proposal deposit, if any, must be settled first.
+    ///
+    /// If the proposal was queued, `transaction` must be the executed [Proposal::queued_transaction].
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+        emit!(ProposalCloseEvent {
+            governor: ctx.accounts.proposal.governor,
+            proposal: ctx.accounts.proposal.key(),
+            payer: ctx.accounts.payer.key(),
+        });
+        Ok(())
+    }
+
+    /// Closes a [Vote] on a [Proposal] which can no longer change, refunding rent to its [Vote::payer].
+    /// Anyone can call this, including after the [Proposal] itself has been closed.
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_vote(ctx: Context<CloseVote>) -> Result<()> {
+        emit!(VoteCloseEvent {
+            proposal: ctx.accounts.vote.proposal,
+            voter: ctx.accounts.vote.voter,
+            payer: ctx.accounts.payer.key(),
+        });
+        Ok(())
+    }
+
+    /// Closes the [ProposalMeta] of a [Proposal] which can no longer change,
+    /// refunding rent to its [ProposalMeta::payer].
+    /// Anyone can call this, including after the [Proposal] itself has been closed.
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_proposal_meta(ctx: Context<CloseProposalMeta>) -> Result<()> {
+        emit!(ProposalMetaCloseEvent {
+            proposal: ctx.accounts.proposal_meta.proposal,
+            payer: ctx.accounts.payer.key(),
+        });
+        Ok(())
+    }
+
+    /// Closes the [VoteRationale] of a [Vote] on a [Proposal] which can no longer change,
+    /// refunding rent to its [VoteRationale::payer]. Anyone can call this.
+    /// The [VoteRationale] must be closed before its [Vote].
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_vote_rationale(ctx: Context<CloseVoteRationale>) -> Result<()> {
+        Ok(())
+    }
 }
 
 /// Errors.
@@ -266,4 +1328,84 @@ pub enum ErrorCode {
     ProposalNotDraft,
     #[msg("The proposal must be active.")]
     ProposalNotActive,
+    #[msg("The proposal was not queued within the grace period and has expired.")]
+    ProposalExpired,
+    #[msg("The governor has already been migrated.")]
+    GovernorAlreadyMigrated,
+    #[msg("The proposal has already been migrated.")]
+    ProposalAlreadyMigrated,
+    #[msg("The governor does not have a guardian.")]
+    GuardianNotSet,
+    #[msg("Only drafts, active, succeeded, or queued proposals can be vetoed.")]
+    ProposalNotVetoable,
+    #[msg("Invalid proposal kind.")]
+    InvalidProposalKind,
+    #[msg("A multiple-choice proposal must have between 2 and 10 options.")]
+    InvalidProposalOptionCount,
+    #[msg("The approval threshold must be at most 10,000 basis points.")]
+    InvalidApprovalThreshold,
+    #[msg("The quorum must be at most 10,000 basis points.")]
+    InvalidQuorumBps,
+    #[msg("The proposal's quorum depends on the total voting power; use activate_proposal_with_voting_power.")]
+    TotalVotingPowerRequired,
+    #[msg("The parameter override of each invoked program must be provided in order of first invocation.")]
+    InvalidParameterOverrides,
+    #[msg("Basis-point quorum overrides require the governor to have a basis-point quorum.")]
+    QuorumBpsOverrideUnsupported,
+    #[msg("The governor requires a proposal deposit.")]
+    ProposalDepositRequired,
+    #[msg("Invalid proposal deposit vault.")]
+    InvalidDepositVault,
+    #[msg("The proposal deposit cannot be settled.")]
+    DepositNotSettleable,
+    #[msg("The proposal is not in a final state.")]
+    ProposalNotFinal,
+    #[msg("The proposal deposit must be settled first.")]
+    DepositNotSettled,
+    #[msg("The vote has already been migrated.")]
+    VoteAlreadyMigrated,
+    #[msg("Only the voter or the electorate may set the vote rationale.")]
+    UnauthorizedVoteRationaleAuthority,
+    #[msg("The vote rationale URI is too long.")]
+    VoteRationaleTooLong,
+    #[msg("The proposal must be sealed before it can be activated.")]
+    ProposalNotSealed,
+    #[msg("The proposal has already been sealed.")]
+    ProposalAlreadySealed,
+    #[msg("The instructions do not fit in the capacity of the proposal.")]
+    ProposalCapacityExceeded,
+    #[msg("The instructions do not match the commitment of the proposal.")]
+    InvalidInstructionsHash,
+    #[msg("The proposal only stores a commitment to its instructions.")]
+    ProposalInstructionsCommitted,
+    #[msg("The proposal does not store a commitment to its instructions.")]
+    ProposalInstructionsNotCommitted,
+    #[msg("The proposer may not create optimistic proposals.")]
+    UnauthorizedOptimisticProposer,
+    #[msg("Too many optimistic proposers.")]
+    TooManyOptimisticProposers,
+    #[msg("Emergency proposals are not enabled.")]
+    EmergencyProposalsDisabled,
+    #[msg("The proposal must be succeeded, or passing early queueing requirements, to be queued.")]
+    ProposalNotQueueable,
+    #[msg("Emergency proposals must have a nonzero quorum and be at least as strict as other proposals, with no longer periods.")]
+    InvalidEmergencyParameters,
+    #[msg("Votes on proposals which may be queued early cannot be changed once cast.")]
+    VoteFinal,
+    #[msg("The proposal creation gate must sign.")]
+    ProposalCreationGateNotSigned,
+    #[msg("The proposer has too many active proposals.")]
+    TooManyActiveProposals,
+    #[msg("Invalid active proposal counter.")]
+    InvalidActiveProposalCounter,
+    #[msg("The proposal must no longer be active to be released.")]
+    ProposalStillActive,
+    #[msg("The proposal must be released from its active proposal counter first.")]
+    ActiveProposalNotReleased,
+    #[msg("There are no pending governor changes which have taken effect.")]
+    NoPendingChangesInEffect,
+    #[msg(
+        "The parameter override must have default parameters and no pending changes to be removed."
+    )]
+    ParameterOverrideNotCleared,
 }
@@ -0,0 +1,411 @@
+//! Timelocked changes to the [Governor] and its [OptimisticConfig].
+
+use crate::*;
+use vipers::unwrap_int;
+
+impl Governor {
+    /// The time at which a change staged at `current_time` takes effect.
+    pub fn pending_change_effective_at(&self, current_time: i64) -> Result<i64> {
+        let delay = unwrap_int!(self.params.params_change_delay_seconds.to_i64());
+        Ok(unwrap_int!(current_time.checked_add(delay)))
+    }
+
+    /// Applies the [Governor::pending_params] if they have taken effect,
+    /// returning the previous [GovernanceParameters].
+    pub fn take_pending_params(&mut self, current_time: i64) -> Option<GovernanceParameters> {
+        if self.pending_params_effective_at == 0 || current_time < self.pending_params_effective_at
+        {
+            return None;
+        }
+        let prev_params = self.params;
+        self.params = self.pending_params;
+        self.pending_params = GovernanceParameters::default();
+        self.pending_params_effective_at = 0;
+        Some(prev_params)
+    }
+
+    /// Applies the [Governor::pending_electorate] if it has taken effect,
+    /// returning the previous electorate.
+    pub fn take_pending_electorate(&mut self, current_time: i64) -> Option<Pubkey> {
+        if self.pending_electorate_effective_at == 0
+            || current_time < self.pending_electorate_effective_at
+        {
+            return None;
+        }
+        let prev_electorate = self.electorate;
+        self.electorate = self.pending_electorate;
+        self.pending_electorate = Pubkey::default();
+        self.pending_electorate_effective_at = 0;
+        Some(prev_electorate)
+    }
+
+    /// Applies the [Governor::pending_emergency_params] if they have taken effect,
+    /// returning the previous [EmergencyParameters].
+    pub fn take_pending_emergency_params(
+        &mut self,
+        current_time: i64,
+    ) -> Option<EmergencyParameters> {
+        if self.pending_emergency_params_effective_at == 0
+            || current_time < self.pending_emergency_params_effective_at
+        {
+            return None;
+        }
+        let prev_params = self.emergency_params;
+        self.emergency_params = self.pending_emergency_params;
+        self.pending_emergency_params = EmergencyParameters::default();
+        self.pending_emergency_params_effective_at = 0;
+        Some(prev_params)
+    }
+
+    /// Applies the [Governor::pending_proposal_creation_gate] if it has taken effect,
+    /// returning the previous gate.
+    pub fn take_pending_proposal_creation_gate(&mut self, current_time: i64) -> Option<Pubkey> {
+        if self.pending_proposal_creation_gate_effective_at == 0
+            || current_time < self.pending_proposal_creation_gate_effective_at
+        {
+            return None;
+        }
+        let prev_gate = self.proposal_creation_gate;
+        self.proposal_creation_gate = self.pending_proposal_creation_gate;
+        self.pending_proposal_creation_gate = Pubkey::default();
+        self.pending_proposal_creation_gate_effective_at = 0;
+        Some(prev_gate)
+    }
+
+    /// Applies the pending proposal deposit if it has taken effect,
+    /// returning the previous [Governor::deposit_mint] and [Governor::deposit_amount].
+    pub fn take_pending_proposal_deposit(&mut self, current_time: i64) -> Option<(Pubkey, u64)> {
+        if self.pending_deposit_effective_at == 0
+            || current_time < self.pending_deposit_effective_at
+        {
+            return None;
+        }
+        let prev_deposit = (self.deposit_mint, self.deposit_amount);
+        self.deposit_mint = self.pending_deposit_mint;
+        self.deposit_amount = self.pending_deposit_amount;
+        self.pending_deposit_mint = Pubkey::default();
+        self.pending_deposit_amount = 0;
+        self.pending_deposit_effective_at = 0;
+        Some(prev_deposit)
+    }
+
+    /// Applies the [Governor::pending_guardian] if it has taken effect,
+    /// returning the previous guardian.
+    pub fn take_pending_guardian(&mut self, current_time: i64) -> Option<Pubkey> {
+        if self.pending_guardian_effective_at == 0
+            || current_time < self.pending_guardian_effective_at
+        {
+            return None;
+        }
+        let prev_guardian = self.guardian;
+        self.guardian = self.pending_guardian;
+        self.pending_guardian = Pubkey::default();
+        self.pending_guardian_effective_at = 0;
+        Some(prev_guardian)
+    }
+}
+
+impl ParameterOverride {
+    /// Applies the [ParameterOverride::pending_params] if they have taken effect,
+    /// returning the previous [OverrideParameters].
+    pub fn take_pending_params(&mut self, current_time: i64) -> Option<OverrideParameters> {
+        if self.pending_effective_at == 0 || current_time < self.pending_effective_at {
+            return None;
+        }
+        let prev_params = self.params;
+        self.params = self.pending_params;
+        self.pending_params = OverrideParameters::default();
+        self.pending_effective_at = 0;
+        Some(prev_params)
+    }
+}
+
+impl OptimisticConfig {
+    /// Applies the pending [OptimisticParameters] and proposers if they have taken effect.
+    /// Returns true if they were applied.
+    pub fn take_pending(&mut self, current_time: i64) -> bool {
+        if self.pending_effective_at == 0 || current_time < self.pending_effective_at {
+            return false;
+        }
+        self.params = self.pending_params;
+        self.proposers = std::mem::take(&mut self.pending_proposers);
+        self.pending_params = OptimisticParameters::default();
+        self.pending_effective_at = 0;
+        true
+    }
+}
+
+/// Applies the pending changes of the [OptimisticConfig] if they have taken effect.
+/// Returns true if they were applied.
+pub(crate) fn apply_pending_optimistic_config(
+    optimistic_config: &mut Account<OptimisticConfig>,
+    current_time: i64,
+) -> bool {
+    if !optimistic_config.take_pending(current_time) {
+        return false;
+    }
+    emit!(OptimisticConfigSetEvent {
+        governor: optimistic_config.governor,
+        params: optimistic_config.params,
+        proposers: optimistic_config.proposers.clone(),
+    });
+    true
+}
+
+/// Applies the pending [OverrideParameters] of a [ParameterOverride] if they have taken effect.
+/// Returns true if they were applied.
+pub(crate) fn apply_pending_parameter_override(
+    parameter_override: &mut Account<ParameterOverride>,
+    current_time: i64,
+) -> bool {
+    let prev_params = match parameter_override.take_pending_params(current_time) {
+        Some(prev_params) => prev_params,
+        None => return false,
+    };
+    emit!(ParameterOverrideSetEvent {
+        governor: parameter_override.governor,
+        program_id: parameter_override.program_id,
+        prev_params,
+        params: parameter_override.params,
+    });
+    true
+}
+
+/// Applies all pending changes of the [Governor] which have taken effect.
+/// Returns true if any change was applied.
+pub(crate) fn apply_pending_changes(governor: &mut Account<Governor>, current_time: i64) -> bool {
+    let governor_key = governor.key();
+
+    let prev_params = governor.take_pending_params(current_time);
+    if let Some(prev_params) = prev_params {
+        emit!(GovernorSetParamsEvent {
+            governor: governor_key,
+            prev_params,
+            params: governor.params,
+        });
+    }
+
+    let prev_electorate = governor.take_pending_electorate(current_time);
+    if let Some(prev_electorate) = prev_electorate {
+        emit!(GovernorSetElectorateEvent {
+            governor: governor_key,
+            prev_electorate,
+            new_electorate: governor.electorate,
+        });
+    }
+
+    let prev_emergency_params = governor.take_pending_emergency_params(current_time);
+    if let Some(prev_params) = prev_emergency_params {
+        emit!(GovernorSetEmergencyParamsEvent {
+            governor: governor_key,
+            prev_params,
+            params: governor.emergency_params,
+        });
+    }
+
+    let prev_gate = governor.take_pending_proposal_creation_gate(current_time);
+    if let Some(prev_gate) = prev_gate {
+        emit!(GovernorSetProposalCreationGateEvent {
+            governor: governor_key,
+            prev_gate,
+            new_gate: governor.proposal_creation_gate,
+        });
+    }
+
+    let prev_deposit = governor.take_pending_proposal_deposit(current_time);
+    if let Some((prev_deposit_mint, prev_deposit_amount)) = prev_deposit {
+        emit!(GovernorSetProposalDepositEvent {
+            governor: governor_key,
+            prev_deposit_mint,
+            prev_deposit_amount,
+            deposit_mint: governor.deposit_mint,
+            deposit_amount: governor.deposit_amount,
+        });
+    }
+
+    let prev_guardian = governor.take_pending_guardian(current_time);
+    if let Some(prev_guardian) = prev_guardian {
+        emit!(GovernorSetGuardianEvent {
+            governor: governor_key,
+            prev_guardian,
+            new_guardian: governor.guardian,
+        });
+    }
+
+    prev_params.is_some()
+        || prev_electorate.is_some()
+        || prev_emergency_params.is_some()
+        || prev_gate.is_some()
+        || prev_deposit.is_some()
+        || prev_guardian.is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pending_params_take_effect_after_delay() {
+        let mut governor = Governor {
+            params: GovernanceParameters {
+                params_change_delay_seconds: 100,
+                ..GovernanceParameters::default()
+            },
+            ..Governor::default()
+        };
+        assert_eq!(governor.take_pending_params(1_000), None);
+
+        governor.pending_params = GovernanceParameters {
+            voting_period: 5,
+            ..GovernanceParameters::default()
+        };
+        governor.pending_params_effective_at = governor.pending_change_effective_at(1_000).unwrap();
+        assert_eq!(governor.take_pending_params(1_099), None);
+
+        let prev_params = governor.take_pending_params(1_100).unwrap();
+        assert_eq!(prev_params.params_change_delay_seconds, 100);
+        assert_eq!(governor.params.voting_period, 5);
+        assert_eq!(governor.pending_params_effective_at, 0);
+        assert_eq!(governor.take_pending_params(1_200), None);
+    }
+
+    #[test]
+    fn test_pending_electorate_takes_effect_after_delay() {
+        let electorate = Pubkey::new_unique();
+        let mut governor = Governor {
+            pending_electorate: electorate,
+            pending_electorate_effective_at: 50,
+            ..Governor::default()
+        };
+        assert_eq!(governor.take_pending_electorate(49), None);
+        assert_eq!(
+            governor.take_pending_electorate(50),
+            Some(Pubkey::default())
+        );
+        assert_eq!(governor.electorate, electorate);
+        assert_eq!(governor.pending_electorate, Pubkey::default());
+    }
+
+    #[test]
+    fn test_pending_proposal_deposit_takes_effect_after_delay() {
+        let deposit_mint = Pubkey::new_unique();
+        let mut governor = Governor {
+            deposit_mint: Pubkey::new_unique(),
+            deposit_amount: 10,
+            pending_deposit_mint: deposit_mint,
+            pending_deposit_amount: 0,
+            pending_deposit_effective_at: 50,
+            ..Governor::default()
+        };
+        assert_eq!(governor.take_pending_proposal_deposit(49), None);
+        assert_eq!(governor.deposit_amount, 10);
+
+        let (_, prev_deposit_amount) = governor.take_pending_proposal_deposit(50).unwrap();
+        assert_eq!(prev_deposit_amount, 10);
+        assert_eq!(governor.deposit_mint, deposit_mint);
+        assert_eq!(governor.deposit_amount, 0);
+        assert_eq!(governor.pending_deposit_effective_at, 0);
+        assert_eq!(governor.take_pending_proposal_deposit(100), None);
+    }
+
+    #[test]
+    fn test_pending_gate_and_emergency_params_take_effect_after_delay() {
+        let gate = Pubkey::new_unique();
+        let emergency_params = EmergencyParameters {
+            approval_threshold_bps: 6_000,
+            ..EmergencyParameters::default()
+        };
+        let mut governor = Governor {
+            pending_proposal_creation_gate: gate,
+            pending_proposal_creation_gate_effective_at: 50,
+            pending_emergency_params: emergency_params,
+            pending_emergency_params_effective_at: 60,
+            ..Governor::default()
+        };
+        assert_eq!(governor.take_pending_proposal_creation_gate(49), None);
+        assert_eq!(governor.take_pending_emergency_params(59), None);
+        assert_eq!(
+            governor.take_pending_proposal_creation_gate(50),
+            Some(Pubkey::default())
+        );
+        assert_eq!(governor.proposal_creation_gate, gate);
+        assert_eq!(
+            governor.take_pending_emergency_params(60),
+            Some(EmergencyParameters::default())
+        );
+        assert_eq!(governor.emergency_params, emergency_params);
+    }
+
+    #[test]
+    fn test_pending_optimistic_config_takes_effect_after_delay() {
+        let proposer = Pubkey::new_unique();
+        let mut optimistic_config = OptimisticConfig {
+            proposers: vec![Pubkey::new_unique()],
+            pending_params: OptimisticParameters {
+                voting_period: 5,
+                veto_votes: 10,
+            },
+            pending_proposers: vec![proposer],
+            pending_effective_at: 50,
+            ..OptimisticConfig::default()
+        };
+        assert!(!optimistic_config.take_pending(49));
+        assert!(optimistic_config.take_pending(50));
+        assert_eq!(optimistic_config.params.veto_votes, 10);
+        assert_eq!(optimistic_config.proposers, vec![proposer]);
+        assert!(optimistic_config.pending_proposers.is_empty());
+        assert!(!optimistic_config.take_pending(100));
+    }
+
+    #[test]
+    fn test_pending_guardian_takes_effect_after_delay() {
+        let guardian = Pubkey::new_unique();
+        let new_guardian = Pubkey::new_unique();
+        let mut governor = Governor {
+            guardian,
+            pending_guardian: new_guardian,
+            pending_guardian_effective_at: 50,
+            ..Governor::default()
+        };
+        assert_eq!(governor.take_pending_guardian(49), None);
+        assert_eq!(governor.guardian, guardian);
+        assert_eq!(governor.take_pending_guardian(50), Some(guardian));
+        assert_eq!(governor.guardian, new_guardian);
+        assert_eq!(governor.pending_guardian_effective_at, 0);
+
+        // removing the guardian is also delayed
+        governor.pending_guardian = Pubkey::default();
+        governor.pending_guardian_effective_at = 100;
+        assert_eq!(governor.take_pending_guardian(99), None);
+        assert_eq!(governor.take_pending_guardian(100), Some(new_guardian));
+        assert_eq!(governor.guardian, Pubkey::default());
+    }
+
+    #[test]
+    fn test_pending_parameter_override_takes_effect_after_delay() {
+        let params = OverrideParameters {
+            voting_period: 5,
+            ..OverrideParameters::default()
+        };
+        let mut parameter_override = ParameterOverride {
+            pending_params: params,
+            pending_effective_at: 50,
+            ..ParameterOverride::default()
+        };
+        assert!(!parameter_override.is_cleared());
+        assert_eq!(parameter_override.take_pending_params(49), None);
+        assert_eq!(
+            parameter_override.take_pending_params(50),
+            Some(OverrideParameters::default())
+        );
+        assert_eq!(parameter_override.params, params);
+        assert!(!parameter_override.is_cleared());
+
+        // clearing the parameters must also wait for the delay before removal
+        parameter_override.pending_params = OverrideParameters::default();
+        parameter_override.pending_effective_at = 100;
+        assert!(!parameter_override.is_cleared());
+        assert_eq!(parameter_override.take_pending_params(100), Some(params));
+        assert!(parameter_override.is_cleared());
+    }
+}
@@ -3,12 +3,10 @@
 use std::convert::TryFrom;
 
 use crate::*;
-use vipers::{program_err, unwrap_int, unwrap_opt};
+use anchor_lang::solana_program::hash::hash;
+use vipers::{assert_keys_eq, invariant, program_err, unwrap_int, unwrap_opt};
 
 /// The state of a proposal.
-///
-/// The `expired` state from Compound is missing here, because the
-/// Smart Wallet handles execution.
 #[derive(Debug, Eq, PartialEq)]
 #[repr(C)]
 pub enum ProposalState {
@@ -23,15 +21,30 @@ pub enum ProposalState {
     /// If a proposal is still a [ProposalState::Draft], a proposal may be canceled by its creator.
     /// A canceled proposal cannot be reactivated; it simply just exists as a record.
     Canceled,
-    /// After the voting period ends, votes are tallied up. A proposal is [ProposalState::Defeated] if one of
-    /// two scenarios happen:
-    /// - More or equal votes are [VoteSide::Against] than [VoteSide::For].
+    /// After the voting period ends, votes are tallied up. A [ProposalKind::Standard] proposal is
+    /// [ProposalState::Defeated] if either:
+    /// - The share of [VoteSide::For] votes out of all [VoteSide::For] and [VoteSide::Against] votes
+    ///   is below [Proposal::approval_threshold_bps]. If the threshold is zero, this is instead when
+    ///   more or equal votes are [VoteSide::Against] than [VoteSide::For].
     /// - The sum of all votes does not meet quorum.
+    ///
+    /// A [ProposalKind::MultipleChoice] proposal is defeated if it does not meet quorum,
+    /// or if no option has any votes or there is a tie for the most votes.
+    /// A [ProposalKind::Optimistic] proposal is defeated only if more than
+    /// [Proposal::veto_votes] are [VoteSide::Against] it.
     Defeated,
     /// A proposal is [ProposalState::Succeeded] if it is not defeated and voting is over.
     Succeeded,
     /// A succeeded proposal may be [ProposalState::Queued] into the [SmartWallet].
     Queued,
+    /// A succeeded proposal that was not queued within [Proposal::grace_period_seconds]
+    /// of voting ending is [ProposalState::Expired]. It may no longer be queued.
+    ///
+    /// Execution itself does not expire here, because the Smart Wallet handles execution.
+    Expired,
+    /// A proposal that has not yet been executed may be [ProposalState::Vetoed] by the [Governor::guardian].
+    /// Like a canceled proposal, a vetoed proposal simply just exists as a record.
+    Vetoed,
 }
 
 /// Side of a vote.
@@ -74,6 +87,56 @@ impl TryFrom<u8> for VoteSide {
     }
 }
 
+/// Maximum number of options on a multiple-choice [Proposal].
+pub const MAX_PROPOSAL_OPTIONS: usize = 10;
+
+/// Kind of a proposal.
+#[derive(Debug, Default, Eq, PartialEq)]
+#[repr(u8)]
+pub enum ProposalKind {
+    /// A proposal that is voted [VoteSide::For], [VoteSide::Against], or [VoteSide::Abstain],
+    /// and which executes [Proposal::instructions] if it succeeds.
+    #[default]
+    Standard = 0,
+    /// A proposal with between 2 and [MAX_PROPOSAL_OPTIONS] [Proposal::options].
+    /// The option with the most votes wins, provided that quorum is met and there is no tie.
+    /// Only the instructions of the winning option are executed.
+    MultipleChoice = 1,
+    /// A proposal that succeeds unless more than [Proposal::veto_votes] are [VoteSide::Against] it.
+    /// Quorum and approval threshold do not apply.
+    Optimistic = 2,
+}
+
+impl From<ProposalKind> for u8 {
+    fn from(kind: ProposalKind) -> Self {
+        kind as u8
+    }
+}
+
+impl TryFrom<u8> for ProposalKind {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(ProposalKind::Standard),
+            1 => Ok(ProposalKind::MultipleChoice),
+            2 => Ok(ProposalKind::Optimistic),
+            _ => program_err!(InvalidProposalKind),
+        }
+    }
+}
+
+/// The outcome of a [Proposal] if voting were to end with its current votes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ProposalOutcome {
+    /// Whether the proposal meets quorum.
+    pub meets_quorum: bool,
+    /// Whether the proposal would pass.
+    pub is_passing: bool,
+    /// The winning option of a multiple-choice proposal.
+    pub winning_option: Option<usize>,
+}
+
 impl Default for ProposalState {
     fn default() -> Self {
         Self::Draft
@@ -81,6 +144,185 @@ impl Default for ProposalState {
 }
 
 impl Proposal {
+    /// Initializes a new [Proposal] under the [Governor], snapshotting the [Governor]'s parameters.
+    pub(crate) fn init(
+        &mut self,
+        governor: &Account<Governor>,
+        proposer: Pubkey,
+        bump: u8,
+    ) -> Result<()> {
+        self.governor = governor.key();
+        self.index = governor.proposal_count;
+        self.bump = bump;
+
+        self.proposer = proposer;
+        self.version = Proposal::VERSION;
+        self.kind = ProposalKind::Standard.into();
+        self.emergency = false;
+        self.emergency_voting_delay = 0;
+
+        self.snapshot_parameters(&governor.params);
+        self.veto_votes = 0;
+        self.total_voting_power = 0;
+        self.voting_extended_seconds = 0;
+        self.created_at = Clock::get()?.unix_timestamp;
+        self.amended_at = 0;
+        self.canceled_at = 0;
+        self.vetoed_at = 0;
+        self.activated_at = 0;
+        self.voting_ends_at = 0;
+
+        self.queued_at = 0;
+        self.queued_transaction = Pubkey::default();
+        self.sealed = true;
+
+        Ok(())
+    }
+
+    /// Snapshots the [Governor]'s parameters onto the [Proposal].
+    pub(crate) fn snapshot_parameters(&mut self, params: &GovernanceParameters) {
+        self.quorum_votes = params.quorum_votes;
+        self.voting_period = params.voting_period;
+        self.timelock_delay_seconds = params.timelock_delay_seconds;
+        self.grace_period_seconds = params.grace_period_seconds;
+        self.approval_threshold_bps = params.approval_threshold_bps;
+        self.quorum_bps = params.quorum_bps;
+        self.vote_extension_seconds = params.vote_extension_seconds;
+        self.max_vote_extension_seconds = params.max_vote_extension_seconds;
+        self.early_queue_enabled = params.early_queue_enabled;
+    }
+
+    /// Snapshots the [Governor]'s emergency parameters onto the [Proposal], making it an emergency proposal.
+    pub(crate) fn snapshot_emergency_parameters(&mut self, params: &EmergencyParameters) {
+        self.emergency = true;
+        self.emergency_voting_delay = params.voting_delay;
+        self.voting_period = params.voting_period;
+        self.quorum_votes = params.quorum_votes;
+        self.quorum_bps = params.quorum_bps;
+        self.approval_threshold_bps = params.approval_threshold_bps;
+        self.timelock_delay_seconds = params.timelock_delay_seconds;
+    }
+
+    /// The time from which the voting delay is counted.
+    /// Amending the instructions of a [Proposal] restarts the voting delay.
+    pub fn voting_delay_started_at(&self) -> i64 {
+        self.created_at.max(self.amended_at)
+    }
+
+    /// Applies the strictest of the proposal's parameters and the [ParameterOverride]s
+    /// of each of the given invoked programs.
+    ///
+    /// `override_accounts` must contain the [ParameterOverride] address of each distinct program,
+    /// in order of first invocation. The address is passed even if no override exists for the program.
+    pub(crate) fn apply_parameter_overrides(
+        &mut self,
+        invoked_program_ids: impl Iterator<Item = Pubkey>,
+        override_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        let mut program_ids: Vec<Pubkey> = vec![];
+        for program_id in invoked_program_ids {
+            if !program_ids.contains(&program_id) {
+                program_ids.push(program_id);
+            }
+        }
+        invariant!(
+            program_ids.len() == override_accounts.len(),
+            InvalidParameterOverrides
+        );
+
+        for (program_id, override_info) in program_ids.iter().zip(override_accounts) {
+            let (override_key, _) = Pubkey::find_program_address(
+                &[
+                    b"TribecaParameterOverride".as_ref(),
+                    self.governor.as_ref(),
+                    program_id.as_ref(),
+                ],
+                &crate::ID,
+            );
+            assert_keys_eq!(override_info.key(), override_key, InvalidParameterOverrides);
+            if override_info.data_is_empty() {
+                continue;
+            }
+            let parameter_override = Account::<ParameterOverride>::try_from(override_info)?;
+            self.apply_parameter_override(&parameter_override.params);
+        }
+        Ok(())
+    }
+
+    /// Checks that the parameters of the proposal are at least as strict as the [ParameterOverride]s
+    /// of each of the given invoked programs. See [Proposal::apply_parameter_overrides].
+    pub(crate) fn meets_parameter_overrides(
+        &self,
+        invoked_program_ids: impl Iterator<Item = Pubkey>,
+        override_accounts: &[AccountInfo],
+    ) -> Result<bool> {
+        let mut strictest = Proposal {
+            governor: self.governor,
+            ..Proposal::default()
+        };
+        strictest.apply_parameter_overrides(invoked_program_ids, override_accounts)?;
+        Ok(self.voting_period >= strictest.voting_period
+            && self.quorum_votes >= strictest.quorum_votes
+            && self.quorum_bps >= strictest.quorum_bps
+            && self.approval_threshold_bps >= strictest.approval_threshold_bps
+            && self.timelock_delay_seconds >= strictest.timelock_delay_seconds)
+    }
+
+    /// Returns true if the proposal only stores a commitment to its instructions.
+    /// See [Proposal::instructions_hash].
+    pub fn is_committed(&self) -> bool {
+        self.instructions_hash != [0; 32]
+    }
+
+    /// Applies the strictest of the proposal's parameters and the given [OverrideParameters].
+    fn apply_parameter_override(&mut self, params: &OverrideParameters) {
+        self.voting_period = self.voting_period.max(params.voting_period);
+        self.quorum_votes = self.quorum_votes.max(params.quorum_votes);
+        self.quorum_bps = self.quorum_bps.max(params.quorum_bps);
+        self.approval_threshold_bps = self
+            .approval_threshold_bps
+            .max(params.approval_threshold_bps);
+        self.timelock_delay_seconds = self
+            .timelock_delay_seconds
+            .max(params.timelock_delay_seconds);
+    }
+
+    /// Returns true if this is a [ProposalKind::Optimistic] proposal.
+    pub fn is_optimistic(&self) -> bool {
+        self.kind == u8::from(ProposalKind::Optimistic)
+    }
+
+    /// Returns true if this is a [ProposalKind::MultipleChoice] proposal.
+    pub fn is_multiple_choice(&self) -> bool {
+        self.kind == u8::from(ProposalKind::MultipleChoice)
+    }
+
+    /// Removes a previously cast vote from the tally of this [Proposal].
+    pub(crate) fn subtract_vote(&mut self, side: u8, vote_weight: u64) -> Result<()> {
+        if !self.is_multiple_choice() {
+            return self.subtract_vote_weight(side.try_into()?, vote_weight);
+        }
+        if side == 0 || vote_weight == 0 {
+            return Ok(());
+        }
+        let option = unwrap_opt!(self.options.get_mut(usize::from(side) - 1), InvalidVoteSide);
+        option.votes = unwrap_int!(option.votes.checked_sub(vote_weight));
+        Ok(())
+    }
+
+    /// Adds a vote to the tally of this [Proposal].
+    pub(crate) fn add_vote(&mut self, side: u8, vote_weight: u64) -> Result<()> {
+        if !self.is_multiple_choice() {
+            return self.add_vote_weight(side.try_into()?, vote_weight);
+        }
+        if side == 0 || vote_weight == 0 {
+            return Ok(());
+        }
+        let option = unwrap_opt!(self.options.get_mut(usize::from(side) - 1), InvalidVoteSide);
+        option.votes = unwrap_int!(option.votes.checked_add(vote_weight));
+        Ok(())
+    }
+
     /// Subtracts from the total weight of a vote for a [Proposal].
     pub(crate) fn subtract_vote_weight(
         &mut self,
@@ -125,6 +367,21 @@ impl Proposal {
         Ok(())
     }
 
+    /// Returns true if the [Proposal] can no longer change state, so its accounts may be closed.
+    ///
+    /// A [ProposalState::Queued] proposal is only final once its queued transaction has been executed,
+    /// since it may otherwise still be vetoed.
+    pub fn is_final(&self, current_time: i64, transaction_executed: bool) -> Option<bool> {
+        Some(match self.state(current_time)? {
+            ProposalState::Canceled
+            | ProposalState::Defeated
+            | ProposalState::Expired
+            | ProposalState::Vetoed => true,
+            ProposalState::Queued => transaction_executed,
+            ProposalState::Draft | ProposalState::Active | ProposalState::Succeeded => false,
+        })
+    }
+
     /// Gets the state.
     pub fn get_state(&self) -> Result<ProposalState> {
         Ok(unwrap_opt!(
@@ -140,62 +397,350 @@ impl Proposal {
             self.for_votes
                 .checked_add(self.against_votes)?
                 .checked_add(self.abstain_votes)?
+                .checked_add(self.total_option_votes()?)?
                 >= quorum_votes,
         )
     }
 
+    /// The number of votes required for the proposal to meet quorum.
+    /// This is the greater of [Proposal::quorum_votes] and [Proposal::quorum_bps] of the total voting power.
+    pub fn quorum_votes_required(&self) -> Option<u64> {
+        if self.quorum_bps == 0 {
+            return Some(self.quorum_votes);
+        }
+        let quorum_bps_votes = (self.total_voting_power as u128)
+            .checked_mul(self.quorum_bps.into())?
+            .checked_div(BPS_DENOMINATOR.into())?
+            .to_u64()?;
+        Some(self.quorum_votes.max(quorum_bps_votes))
+    }
+
+    /// Checks if the proposal meets its approval threshold; that is,
+    /// enough of the [VoteSide::For] and [VoteSide::Against] votes are [VoteSide::For].
+    pub fn meets_approval_threshold(&self, approval_threshold_bps: u16) -> Option<bool> {
+        if approval_threshold_bps == 0 {
+            return Some(self.for_votes > self.against_votes);
+        }
+        let decided_votes = (self.for_votes as u128).checked_add(self.against_votes.into())?;
+        Some(
+            self.for_votes > 0
+                && (self.for_votes as u128).checked_mul(BPS_DENOMINATOR.into())?
+                    >= decided_votes.checked_mul(approval_threshold_bps.into())?,
+        )
+    }
+
+    /// Total number of votes made on the options of a multiple-choice proposal.
+    pub fn total_option_votes(&self) -> Option<u64> {
+        self.options
+            .iter()
+            .try_fold(0_u64, |acc, option| acc.checked_add(option.votes))
+    }
+
+    /// The index of the option of a multiple-choice proposal with the most votes.
+    /// Returns [None] if no option has any votes or if there is a tie for the most votes.
+    pub fn winning_option(&self) -> Option<usize> {
+        let (index, max_votes) = self
+            .options
+            .iter()
+            .enumerate()
+            .map(|(index, option)| (index, option.votes))
+            .max_by_key(|&(_, votes)| votes)?;
+        if max_votes == 0
+            || self
+                .options
+                .iter()
+                .filter(|option| option.votes == max_votes)
+                .count()
+                > 1
+        {
+            return None;
+        }
+        Some(index)
+    }
+
+    /// Checks if the proposal would pass if voting ended with the current votes.
+    pub fn is_passing(&self) -> Option<bool> {
+        if self.is_optimistic() {
+            return Some(self.against_votes <= self.veto_votes);
+        }
+        if !self.meets_quorum(self.quorum_votes_required()?)? {
+            return Some(false);
+        }
+        if self.is_multiple_choice() {
+            return Some(self.winning_option().is_some());
+        }
+        self.meets_approval_threshold(self.approval_threshold_bps)
+    }
+
     /// The state of the proposal. See [ProposalState] for more details.
     /// Adapted from <https://github.com/compound-finance/compound-protocol/blob/4a8648ec0364d24c4ecfc7d6cae254f55030d65f/contracts/Governance/GovernorBravoDelegate.sol#L205>
     pub fn state(&self, current_time: i64) -> Option<ProposalState> {
         if self.canceled_at > 0 {
             return Some(ProposalState::Canceled);
+        } else if self.vetoed_at > 0 {
+            return Some(ProposalState::Vetoed);
         } else if self.activated_at == 0 {
             return Some(ProposalState::Draft);
         } else if current_time < self.voting_ends_at {
             return Some(ProposalState::Active);
-        } else if self.for_votes <= self.against_votes || !self.meets_quorum(self.quorum_votes)? {
+        } else if !self.is_passing()? {
             return Some(ProposalState::Defeated);
         } else if self.queued_at > 0 {
             return Some(ProposalState::Queued);
+        } else if self.grace_period_seconds > 0
+            && current_time
+                >= self
+                    .voting_ends_at
+                    .checked_add(self.grace_period_seconds.to_i64()?)?
+        {
+            return Some(ProposalState::Expired);
         }
         Some(ProposalState::Succeeded)
     }
 
+    /// An upper bound on the number of votes which have not yet been cast,
+    /// based on the total voting power reported by the electorate.
+    pub fn max_remaining_votes(&self) -> Option<u64> {
+        let cast_votes = self
+            .for_votes
+            .checked_add(self.against_votes)?
+            .checked_add(self.abstain_votes)?
+            .checked_add(self.total_option_votes()?)?;
+        Some(self.total_voting_power.saturating_sub(cast_votes))
+    }
+
+    /// Returns true if votes on the proposal may not be changed once cast.
+    ///
+    /// This is the case for proposals which may be queued early, since
+    /// [Proposal::is_decided] assumes that cast votes are final.
+    pub fn has_final_votes(&self) -> bool {
+        self.early_queue_enabled && self.total_voting_power > 0
+    }
+
+    /// Checks if the outcome of the proposal is decided; that is, the proposal would pass
+    /// even if all of the `max_remaining_votes` were cast in the way most harmful to it.
+    /// Cast votes are assumed to be final; see [Proposal::has_final_votes].
+    pub fn is_decided(&self, max_remaining_votes: u64) -> Option<bool> {
+        if self.is_optimistic() {
+            return Some(self.against_votes.checked_add(max_remaining_votes)? <= self.veto_votes);
+        }
+        if !self.meets_quorum(self.quorum_votes_required()?)? {
+            return Some(false);
+        }
+        if self.is_multiple_choice() {
+            let winner = match self.winning_option() {
+                Some(winner) => winner,
+                None => return Some(false),
+            };
+            let winner_votes = self.options[winner].votes;
+            let runner_up_votes = self
+                .options
+                .iter()
+                .enumerate()
+                .filter(|&(index, _)| index != winner)
+                .map(|(_, option)| option.votes)
+                .max()?;
+            return Some(winner_votes > runner_up_votes.checked_add(max_remaining_votes)?);
+        }
+        let worst_case = Proposal {
+            for_votes: self.for_votes,
+            against_votes: self.against_votes.checked_add(max_remaining_votes)?,
+            ..Proposal::default()
+        };
+        worst_case.meets_approval_threshold(self.approval_threshold_bps)
+    }
+
+    /// Returns true if the proposal may be vetoed by the [Governor::guardian];
+    /// that is, if it is a [ProposalState::Draft], [ProposalState::Active],
+    /// [ProposalState::Succeeded] or [ProposalState::Queued].
+    pub fn is_vetoable(&self, current_time: i64) -> Option<bool> {
+        Some(matches!(
+            self.state(current_time)?,
+            ProposalState::Draft
+                | ProposalState::Active
+                | ProposalState::Succeeded
+                | ProposalState::Queued
+        ))
+    }
+
+    /// Returns true if the proposal may be queued.
+    ///
+    /// Emergency proposals may be queued while still [ProposalState::Active] as soon as they pass,
+    /// and other proposals may be if early queueing is enabled and their outcome is decided.
+    pub fn is_queueable(&self, current_time: i64) -> Option<bool> {
+        Some(match self.state(current_time)? {
+            ProposalState::Succeeded => true,
+            ProposalState::Active => {
+                (self.emergency && self.is_passing()?)
+                    || (self.has_final_votes() && self.is_decided(self.max_remaining_votes()?)?)
+            }
+            _ => false,
+        })
+    }
+
+    /// The outcome of the proposal if voting ended with the current votes.
+    pub fn outcome(&self) -> Option<ProposalOutcome> {
+        Some(ProposalOutcome {
+            meets_quorum: self.meets_quorum(self.quorum_votes_required()?)?,
+            is_passing: self.is_passing()?,
+            winning_option: self.winning_option(),
+        })
+    }
+
+    /// The number of seconds that voting should be extended by, given that a vote at `now`
+    /// changed the outcome of the proposal from `prev_outcome` to the current outcome.
+    ///
+    /// Voting is extended if the vote was cast within the final [Proposal::vote_extension_seconds]
+    /// of voting and it caused the proposal to first reach quorum or changed the outcome.
+    /// Voting then ends [Proposal::vote_extension_seconds] after the vote, up to a total
+    /// extension of [Proposal::max_vote_extension_seconds].
+    pub fn vote_extension(&self, now: i64, prev_outcome: ProposalOutcome) -> Option<u64> {
+        if self.vote_extension_seconds == 0 {
+            return Some(0);
+        }
+        let outcome = self.outcome()?;
+        let first_reached_quorum = !prev_outcome.meets_quorum && outcome.meets_quorum;
+        let outcome_changed = prev_outcome.is_passing != outcome.is_passing
+            || prev_outcome.winning_option != outcome.winning_option;
+        if !first_reached_quorum && !outcome_changed {
+            return Some(0);
+        }
+
+        let next_voting_ends_at = now.checked_add(self.vote_extension_seconds.to_i64()?)?;
+        if next_voting_ends_at <= self.voting_ends_at {
+            return Some(0);
+        }
+        let extension = next_voting_ends_at
+            .checked_sub(self.voting_ends_at)?
+            .to_u64()?;
+        let remaining_extension = self
+            .max_vote_extension_seconds
+            .saturating_sub(self.voting_extended_seconds);
+        Some(extension.min(remaining_extension))
+    }
+
+    /// Extends voting by `extension` seconds.
+    pub(crate) fn extend_voting(&mut self, extension: u64) -> Result<()> {
+        self.voting_ends_at = unwrap_int!(extension
+            .to_i64()
+            .and_then(|v: i64| self.voting_ends_at.checked_add(v)));
+        self.voting_extended_seconds =
+            unwrap_int!(self.voting_extended_seconds.checked_add(extension));
+        Ok(())
+    }
+
+    /// The instructions to execute if this proposal succeeds.
+    /// For a multiple-choice proposal, these are the instructions of the winning option.
+    pub fn executable_instructions(&self) -> &[ProposalInstruction] {
+        if !self.is_multiple_choice() {
+            return &self.instructions;
+        }
+        match self.winning_option() {
+            Some(index) => &self.options[index].instructions,
+            None => &[],
+        }
+    }
+
     /// Converts this proposal to Smart Wallet [smart_wallet::TXInstruction]s.
     pub fn to_smart_wallet_instructions(&self) -> Vec<smart_wallet::TXInstruction> {
-        self.instructions
-            .iter()
-            .map(
-                |ProposalInstruction {
-                     program_id,
-                     keys,
-                     data,
-                 }| smart_wallet::TXInstruction {
-                    program_id: *program_id,
-                    keys: keys
-                        .iter()
-                        .map(
-                            |&ProposalAccountMeta {
-                                 pubkey,
-                                 is_signer,
-                                 is_writable,
-                             }| smart_wallet::TXAccountMeta {
-                                pubkey,
-                                is_signer,
-                                is_writable,
-                            },
-                        )
-                        .collect(),
-                    data: data.clone(),
-                },
-            )
-            .collect()
+        to_smart_wallet_instructions(self.executable_instructions())
+    }
+}
+
+/// Computes the SHA-256 hash of the serialized instructions.
+pub fn hash_instructions(instructions: &[ProposalInstruction]) -> Result<[u8; 32]> {
+    Ok(hash(&instructions.try_to_vec()?).to_bytes())
+}
+
+/// Converts [ProposalInstruction]s to Smart Wallet [smart_wallet::TXInstruction]s.
+pub fn to_smart_wallet_instructions(
+    instructions: &[ProposalInstruction],
+) -> Vec<smart_wallet::TXInstruction> {
+    instructions
+        .iter()
+        .map(
+            |ProposalInstruction {
+                 program_id,
+                 keys,
+                 data,
+             }| smart_wallet::TXInstruction {
+                program_id: *program_id,
+                keys: keys
+                    .iter()
+                    .map(
+                        |&ProposalAccountMeta {
+                             pubkey,
+                             is_signer,
+                             is_writable,
+                         }| smart_wallet::TXAccountMeta {
+                            pubkey,
+                            is_signer,
+                            is_writable,
+                        },
+                    )
+                    .collect(),
+                data: data.clone(),
+            },
+        )
+        .collect()
+}
+
+impl<'info> ActivateProposal<'info> {
+    /// Activates the [Proposal], starting the voting period.
+    ///
+    /// If the [Governor] limits the number of active proposals per proposer,
+    /// the first remaining account must be the proposer's [ActiveProposalCounter].
+    pub fn activate(
+        &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
+        total_voting_power: u64,
+    ) -> Result<()> {
+        let max_active_proposals = self.governor.params.max_active_proposals_per_proposer;
+        if max_active_proposals > 0 {
+            let counter_info =
+                unwrap_opt!(remaining_accounts.first(), InvalidActiveProposalCounter);
+            let mut counter: Account<ActiveProposalCounter> = Account::try_from(counter_info)?;
+            assert_keys_eq!(
+                counter.governor,
+                self.governor,
+                InvalidActiveProposalCounter
+            );
+            assert_keys_eq!(
+                counter.proposer,
+                self.proposal.proposer,
+                InvalidActiveProposalCounter
+            );
+            counter.increment(max_active_proposals)?;
+            counter.exit(&crate::ID)?;
+            self.proposal.counted_as_active = true;
+        }
+
+        let proposal = &mut self.proposal;
+        let now = Clock::get()?.unix_timestamp;
+        proposal.activated_at = now;
+        proposal.voting_ends_at = unwrap_int!(proposal
+            .voting_period
+            .to_i64()
+            .and_then(|v: i64| now.checked_add(v)));
+        proposal.total_voting_power = total_voting_power;
+
+        emit!(ProposalActivateEvent {
+            governor: proposal.governor,
+            proposal: proposal.key(),
+            voting_ends_at: proposal.voting_ends_at,
+        });
+
+        Ok(())
     }
 }
 
 impl<'info> QueueProposal<'info> {
     /// Queues a Transaction into the Smart Wallet.
-    pub fn queue_transaction(&mut self, tx_bump: u8) -> Result<()> {
+    pub fn queue_transaction(
+        &mut self,
+        tx_bump: u8,
+        instructions: Vec<smart_wallet::TXInstruction>,
+    ) -> Result<()> {
         let seeds = governor_seeds!(self.governor);
         let signer_seeds = &[&seeds[..]];
         let cpi_ctx = CpiContext::new_with_signer(
@@ -211,32 +756,54 @@ impl<'info> QueueProposal<'info> {
         );
 
         // no delay
-        if self.governor.params.timelock_delay_seconds == 0 {
-            smart_wallet::cpi::create_transaction(
-                cpi_ctx,
-                tx_bump,
-                self.proposal.to_smart_wallet_instructions(),
-            )?;
+        if self.proposal.timelock_delay_seconds == 0 {
+            smart_wallet::cpi::create_transaction(cpi_ctx, tx_bump, instructions)?;
         } else {
             // delay; calculate ETA
             smart_wallet::cpi::create_transaction_with_timelock(
                 cpi_ctx,
                 tx_bump,
-                self.proposal.to_smart_wallet_instructions(),
+                instructions,
                 unwrap_int!(Clock::get()?
                     .unix_timestamp
-                    .checked_add(self.governor.params.timelock_delay_seconds)),
+                    .checked_add(self.proposal.timelock_delay_seconds)),
             )?;
         }
 
         let proposal = &mut self.proposal;
-        proposal.queued_at = Clock::get()?.unix_timestamp;
+        let now = Clock::get()?.unix_timestamp;
+        // emergency proposals queued early stop accepting votes
+        if proposal.voting_ends_at > now {
+            proposal.voting_ends_at = now;
+        }
+        proposal.queued_at = now;
         proposal.queued_transaction = self.transaction.key();
 
         Ok(())
     }
 }
 
+impl<'info> VetoProposal<'info> {
+    /// Revokes the [Governor]'s approval of the queued Smart Wallet transaction,
+    /// preventing it from being executed.
+    ///
+    /// The `transaction` must be the [Proposal::queued_transaction].
+    pub fn unapprove_transaction(&self, transaction: &AccountInfo<'info>) -> Result<()> {
+        assert_keys_eq!(transaction.key(), self.proposal.queued_transaction);
+        let seeds = governor_seeds!(self.governor);
+        let signer_seeds = &[&seeds[..]];
+        smart_wallet::cpi::unapprove(CpiContext::new_with_signer(
+            self.smart_wallet_program.to_account_info(),
+            smart_wallet::cpi::accounts::Approve {
+                smart_wallet: self.smart_wallet.to_account_info(),
+                transaction: transaction.clone(),
+                owner: self.governor.to_account_info(),
+            },
+            signer_seeds,
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -270,6 +837,7 @@ mod tests {
     #[derive(Default)]
     struct TestProposalParams {
         pub canceled_at: i64,
+        pub vetoed_at: i64,
         pub current_ts: i64,
         pub activated_at: i64,
         pub created_at: i64,
@@ -279,6 +847,10 @@ mod tests {
         pub against_votes: u64,
         pub for_votes: u64,
         pub quorum_votes: u64,
+        pub grace_period_seconds: u64,
+        pub approval_threshold_bps: u16,
+        pub quorum_bps: u16,
+        pub total_voting_power: u64,
     }
 
     fn test_proposal_state(t: TestProposalParams) -> ProposalState {
@@ -287,11 +859,16 @@ mod tests {
             against_votes: t.against_votes,
             abstain_votes: t.abstain_votes,
             canceled_at: t.canceled_at,
+            vetoed_at: t.vetoed_at,
             created_at: t.created_at,
             activated_at: t.activated_at,
             voting_ends_at: t.voting_ends_at,
             queued_at: t.queued_at,
             quorum_votes: t.quorum_votes,
+            grace_period_seconds: t.grace_period_seconds,
+            approval_threshold_bps: t.approval_threshold_bps,
+            quorum_bps: t.quorum_bps,
+            total_voting_power: t.total_voting_power,
             ..Proposal::default()
         };
 
@@ -320,6 +897,26 @@ mod tests {
         }
     }
 
+    proptest! {
+        #[test]
+        fn test_vetoed_state(
+            vetoed_at in 1..=i64::MAX,
+            activated_at in 0..=i64::MAX,
+            queued_at in 0..=i64::MAX,
+            (current_ts, voting_ends_at) in total_and_intermediate_ts(),
+        ) {
+            let params = TestProposalParams {
+                vetoed_at,
+                activated_at,
+                queued_at,
+                current_ts,
+                voting_ends_at,
+                ..TestProposalParams::default()
+            };
+            assert_eq!(test_proposal_state(params), ProposalState::Vetoed);
+        }
+    }
+
     proptest! {
         #[test]
         fn test_active_state(
@@ -412,6 +1009,129 @@ mod tests {
         }
     }
 
+    proptest! {
+        #[test]
+        fn test_optimistic_state(
+            activated_at in 1..=i64::MAX,
+            (current_ts, voting_ends_at) in total_and_intermediate_ts(),
+            for_votes: u64,
+            (against_votes, veto_votes) in part_and_total(),
+        ) {
+            let mut proposal = Proposal {
+                kind: ProposalKind::Optimistic.into(),
+                activated_at,
+                voting_ends_at,
+                for_votes,
+                against_votes,
+                veto_votes,
+                ..Proposal::default()
+            };
+            assert_eq!(proposal.state(current_ts).unwrap(), ProposalState::Succeeded);
+
+            proposal.against_votes = veto_votes + 1;
+            assert_eq!(proposal.state(current_ts).unwrap(), ProposalState::Defeated);
+        }
+    }
+
+    #[test]
+    fn test_emergency_queueable_before_voting_ends() {
+        let mut proposal = Proposal {
+            activated_at: 1,
+            voting_ends_at: 100,
+            for_votes: 90,
+            against_votes: 10,
+            quorum_votes: 100,
+            approval_threshold_bps: 9_000,
+            ..Proposal::default()
+        };
+        assert_eq!(proposal.is_queueable(50), Some(false));
+        assert_eq!(proposal.is_queueable(100), Some(true));
+
+        proposal.emergency = true;
+        assert_eq!(proposal.is_queueable(50), Some(true));
+
+        proposal.against_votes = 11;
+        assert_eq!(proposal.is_queueable(50), Some(false));
+    }
+
+    proptest! {
+        #[test]
+        fn test_decided_survives_remaining_votes(
+            (against_votes, for_votes) in part_and_total(),
+            max_remaining_votes in 0..MAX_TOTAL_TOKENS,
+            approval_threshold_bps in 0..=BPS_DENOMINATOR as u16,
+        ) {
+            let proposal = Proposal {
+                for_votes,
+                against_votes,
+                approval_threshold_bps,
+                ..Proposal::default()
+            };
+            if proposal.is_decided(max_remaining_votes).unwrap() {
+                let worst_case = Proposal {
+                    against_votes: against_votes + max_remaining_votes,
+                    ..proposal
+                };
+                assert!(worst_case.is_passing().unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn test_early_queue_when_decided() {
+        let mut proposal = Proposal {
+            activated_at: 1,
+            voting_ends_at: 100,
+            for_votes: 60,
+            against_votes: 10,
+            quorum_votes: 50,
+            total_voting_power: 100,
+            ..Proposal::default()
+        };
+        assert_eq!(proposal.max_remaining_votes(), Some(30));
+        assert_eq!(proposal.is_queueable(50), Some(false));
+        assert!(!proposal.has_final_votes());
+
+        proposal.early_queue_enabled = true;
+        assert!(proposal.has_final_votes());
+        assert_eq!(proposal.is_queueable(50), Some(true));
+
+        proposal.for_votes = 50;
+        assert_eq!(proposal.is_queueable(50), Some(false));
+    }
+
+    #[test]
+    fn test_emergency_parameters_snapshotted() {
+        let params = EmergencyParameters {
+            voting_delay: 10,
+            voting_period: 20,
+            quorum_votes: 30,
+            quorum_bps: 40,
+            approval_threshold_bps: 5_000,
+            timelock_delay_seconds: 60,
+        };
+        let mut proposal = Proposal::default();
+        proposal.snapshot_emergency_parameters(&params);
+        assert!(proposal.emergency);
+        assert_eq!(proposal.emergency_voting_delay, 10);
+        assert_eq!(proposal.voting_period, 20);
+        assert_eq!(proposal.timelock_delay_seconds, 60);
+    }
+
+    #[test]
+    fn test_queued_is_final_once_executed() {
+        let proposal = Proposal {
+            activated_at: 1,
+            voting_ends_at: 10,
+            queued_at: 10,
+            for_votes: 100,
+            quorum_votes: 100,
+            ..Proposal::default()
+        };
+        assert_eq!(proposal.is_final(20, false), Some(false));
+        assert_eq!(proposal.is_final(20, true), Some(true));
+    }
+
     proptest! {
         #[test]
         fn test_success_state(
@@ -430,4 +1150,566 @@ mod tests {
             assert_eq!(test_proposal_state(params), ProposalState::Succeeded);
         }
     }
+
+    proptest! {
+        #[test]
+        fn test_success_state_within_grace_period(
+            activated_at in 1..=i64::MAX,
+            (quorum_votes, for_votes) in part_and_total(),
+            (current_ts, voting_ends_at) in total_and_intermediate_ts(),
+            extra_grace_seconds in 1..=MAX_SECONDS_BETWEEN_CHECKPOINTS,
+        ) {
+            let params = TestProposalParams {
+                activated_at,
+                current_ts,
+                for_votes,
+                quorum_votes,
+                voting_ends_at,
+                grace_period_seconds: (current_ts - voting_ends_at + extra_grace_seconds) as u64,
+                ..TestProposalParams::default()
+            };
+            assert_eq!(test_proposal_state(params), ProposalState::Succeeded);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_expired_state(
+            activated_at in 1..=i64::MAX,
+            (quorum_votes, for_votes) in part_and_total(),
+            (current_ts, voting_ends_at) in total_and_intermediate_ts(),
+            grace_period_seconds in 1..=MAX_SECONDS_BETWEEN_CHECKPOINTS,
+        ) {
+            prop_assume!(current_ts - voting_ends_at >= grace_period_seconds);
+            let params = TestProposalParams {
+                activated_at,
+                current_ts,
+                for_votes,
+                quorum_votes,
+                voting_ends_at,
+                grace_period_seconds: grace_period_seconds as u64,
+                ..TestProposalParams::default()
+            };
+            assert_eq!(test_proposal_state(params), ProposalState::Expired);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_queued_state_does_not_expire(
+            activated_at in 1..=i64::MAX,
+            queued_at in 1..i64::MAX,
+            (quorum_votes, for_votes) in part_and_total(),
+            (current_ts, voting_ends_at) in total_and_intermediate_ts(),
+            grace_period_seconds in 1..=MAX_SECONDS_BETWEEN_CHECKPOINTS,
+        ) {
+            let params = TestProposalParams {
+                activated_at,
+                current_ts,
+                for_votes,
+                quorum_votes,
+                voting_ends_at,
+                queued_at,
+                grace_period_seconds: grace_period_seconds as u64,
+                ..TestProposalParams::default()
+            };
+            assert_eq!(test_proposal_state(params), ProposalState::Queued);
+        }
+    }
+
+    #[test]
+    fn test_vetoable_states() {
+        let draft = Proposal::default();
+        assert_eq!(draft.state(50), Some(ProposalState::Draft));
+        assert_eq!(draft.is_vetoable(50), Some(true));
+
+        let active = Proposal {
+            activated_at: 1,
+            voting_ends_at: 100,
+            for_votes: 100,
+            quorum_votes: 100,
+            ..Proposal::default()
+        };
+        assert_eq!(active.state(50), Some(ProposalState::Active));
+        assert_eq!(active.is_vetoable(50), Some(true));
+        assert_eq!(active.state(100), Some(ProposalState::Succeeded));
+        assert_eq!(active.is_vetoable(100), Some(true));
+
+        let queued = Proposal {
+            queued_at: 100,
+            ..active.clone()
+        };
+        assert_eq!(queued.state(100), Some(ProposalState::Queued));
+        assert_eq!(queued.is_vetoable(100), Some(true));
+
+        let defeated = Proposal {
+            for_votes: 99,
+            ..active.clone()
+        };
+        assert_eq!(defeated.state(100), Some(ProposalState::Defeated));
+        assert_eq!(defeated.is_vetoable(100), Some(false));
+
+        let expired = Proposal {
+            grace_period_seconds: 10,
+            ..active
+        };
+        assert_eq!(expired.state(110), Some(ProposalState::Expired));
+        assert_eq!(expired.is_vetoable(110), Some(false));
+
+        let canceled = Proposal {
+            canceled_at: 1,
+            ..Proposal::default()
+        };
+        assert_eq!(canceled.is_vetoable(50), Some(false));
+
+        let vetoed = Proposal {
+            vetoed_at: 60,
+            ..queued
+        };
+        assert_eq!(vetoed.is_vetoable(100), Some(false));
+    }
+
+    #[test]
+    fn test_legacy_proposal_migration() {
+        let instructions = vec![ProposalInstruction {
+            program_id: Pubkey::new_unique(),
+            keys: vec![],
+            data: vec![1, 2, 3],
+        }];
+        let proposer = Pubkey::new_unique();
+        let legacy = LegacyProposal {
+            proposer,
+            quorum_votes: 100,
+            for_votes: 100,
+            created_at: 1,
+            activated_at: 500,
+            voting_ends_at: 1_000,
+            instructions: instructions.clone(),
+            ..LegacyProposal::default()
+        };
+        let mut legacy_data = legacy.try_to_vec().unwrap();
+        // legacy proposals were allocated with zeroed slack after their instructions
+        legacy_data.extend([0; 16]);
+        let rest = &mut legacy_data.as_slice();
+        LegacyProposal::deserialize(rest).unwrap();
+        assert_eq!(rest[0], 0);
+
+        let params = GovernanceParameters {
+            voting_period: 500,
+            timelock_delay_seconds: 60,
+            grace_period_seconds: 600,
+            ..GovernanceParameters::default()
+        };
+        let migrated_data = legacy.into_proposal(&params).try_to_vec().unwrap();
+        let rest = &mut migrated_data.as_slice();
+        LegacyProposal::deserialize(rest).unwrap();
+        assert_eq!(rest[0], Proposal::VERSION);
+
+        let proposal = Proposal::deserialize(&mut migrated_data.as_slice()).unwrap();
+        assert_eq!(proposal.proposer, proposer);
+        assert_eq!(proposal.payer, proposer);
+        assert_eq!(proposal.for_votes, 100);
+        assert_eq!(proposal.voting_ends_at, 1_000);
+        assert_eq!(proposal.instructions, instructions);
+        assert_eq!(proposal.voting_period, 500);
+        assert_eq!(proposal.timelock_delay_seconds, 60);
+        assert_eq!(proposal.grace_period_seconds, 600);
+        assert!(proposal.sealed);
+        assert_eq!(proposal.state(1_599).unwrap(), ProposalState::Succeeded);
+        assert_eq!(proposal.is_queueable(1_599), Some(true));
+        assert_eq!(proposal.state(1_600).unwrap(), ProposalState::Expired);
+        assert_eq!(proposal.is_queueable(1_600), Some(false));
+    }
+
+    proptest! {
+        #[test]
+        fn test_not_meet_quorum_bps(
+            activated_at in 1..=i64::MAX,
+            (all_votes, total_voting_power) in part_and_total(),
+            (current_ts, voting_ends_at) in total_and_intermediate_ts(),
+            quorum_bps in 1..=BPS_DENOMINATOR as u16,
+        ) {
+            let quorum_votes_required = (total_voting_power as u128 * quorum_bps as u128
+                / BPS_DENOMINATOR as u128) as u64;
+            prop_assume!(all_votes < quorum_votes_required);
+            let params = TestProposalParams {
+                current_ts,
+                activated_at,
+                voting_ends_at,
+                for_votes: all_votes,
+                quorum_bps,
+                total_voting_power,
+                ..TestProposalParams::default()
+            };
+            assert_eq!(test_proposal_state(params), ProposalState::Defeated);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_meets_quorum_bps(
+            activated_at in 1..=i64::MAX,
+            (for_votes, total_voting_power) in part_and_total(),
+            (current_ts, voting_ends_at) in total_and_intermediate_ts(),
+            quorum_bps in 1..=BPS_DENOMINATOR as u16,
+            quorum_votes_seed in 0..=u64::MAX,
+        ) {
+            let quorum_votes_required = (total_voting_power as u128 * quorum_bps as u128
+                / BPS_DENOMINATOR as u128) as u64;
+            prop_assume!(for_votes > 0 && for_votes >= quorum_votes_required);
+            // both quorums must be met
+            let quorum_votes = quorum_votes_seed % (for_votes + 1);
+            let params = TestProposalParams {
+                current_ts,
+                activated_at,
+                voting_ends_at,
+                for_votes,
+                quorum_votes,
+                quorum_bps,
+                total_voting_power,
+                ..TestProposalParams::default()
+            };
+            assert_eq!(test_proposal_state(params), ProposalState::Succeeded);
+        }
+    }
+
+    prop_compose! {
+        pub fn votes_and_threshold()(
+            approval_threshold_bps in 1..=BPS_DENOMINATOR as u16,
+            decided_votes in 1..MAX_TOTAL_TOKENS,
+        ) -> (u64, u64, u16) {
+            // smallest number of for votes meeting the threshold
+            let threshold_votes = ((decided_votes as u128 * approval_threshold_bps as u128
+                + BPS_DENOMINATOR as u128
+                - 1)
+                / BPS_DENOMINATOR as u128) as u64;
+            (threshold_votes, decided_votes, approval_threshold_bps)
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_meets_approval_threshold(
+            (threshold_votes, decided_votes, approval_threshold_bps) in votes_and_threshold(),
+            extra_for_votes_bps in 0..=BPS_DENOMINATOR,
+            activated_at in 1..=i64::MAX,
+            (current_ts, voting_ends_at) in total_and_intermediate_ts(),
+        ) {
+            let for_votes = (threshold_votes
+                + ((decided_votes - threshold_votes) as u128 * extra_for_votes_bps as u128
+                    / BPS_DENOMINATOR as u128) as u64)
+                .max(1);
+            let against_votes = decided_votes.saturating_sub(for_votes);
+            let params = TestProposalParams {
+                current_ts,
+                activated_at,
+                voting_ends_at,
+                for_votes,
+                against_votes,
+                approval_threshold_bps,
+                ..TestProposalParams::default()
+            };
+            let proposal = Proposal {
+                for_votes,
+                against_votes,
+                ..Proposal::default()
+            };
+            assert!(proposal.meets_approval_threshold(approval_threshold_bps).unwrap(), "proposal should meet threshold; for_votes: {}, against_votes: {}, threshold: {}", for_votes, against_votes, approval_threshold_bps);
+            assert_eq!(test_proposal_state(params), ProposalState::Succeeded);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_not_meet_approval_threshold(
+            (threshold_votes, decided_votes, approval_threshold_bps) in votes_and_threshold(),
+            missing_votes in 1..MAX_TOTAL_TOKENS,
+            abstain_votes in 0..MAX_TOTAL_TOKENS,
+            activated_at in 1..=i64::MAX,
+            (current_ts, voting_ends_at) in total_and_intermediate_ts(),
+        ) {
+            prop_assume!(threshold_votes > 0);
+            let for_votes = threshold_votes - 1 - (missing_votes % threshold_votes).min(threshold_votes - 1);
+            let against_votes = decided_votes - for_votes;
+            let params = TestProposalParams {
+                current_ts,
+                activated_at,
+                voting_ends_at,
+                for_votes,
+                against_votes,
+                abstain_votes,
+                approval_threshold_bps,
+                ..TestProposalParams::default()
+            };
+            let proposal = Proposal {
+                for_votes,
+                against_votes,
+                abstain_votes,
+                ..Proposal::default()
+            };
+            assert!(!proposal.meets_approval_threshold(approval_threshold_bps).unwrap(), "proposal should fail threshold; for_votes: {}, against_votes: {}, threshold: {}", for_votes, against_votes, approval_threshold_bps);
+            assert_eq!(test_proposal_state(params), ProposalState::Defeated);
+        }
+    }
+
+    fn multiple_choice_proposal(option_votes: &[u64], quorum_votes: u64) -> Proposal {
+        Proposal {
+            kind: ProposalKind::MultipleChoice.into(),
+            activated_at: 1,
+            voting_ends_at: 2,
+            quorum_votes,
+            options: option_votes
+                .iter()
+                .enumerate()
+                .map(|(i, &votes)| ProposalOption {
+                    votes,
+                    instructions: vec![ProposalInstruction {
+                        data: vec![i as u8],
+                        ..ProposalInstruction::default()
+                    }],
+                })
+                .collect(),
+            ..Proposal::default()
+        }
+    }
+
+    #[test]
+    fn test_multiple_choice_votes() {
+        let mut proposal = multiple_choice_proposal(&[0, 0, 0], 0);
+        proposal.add_vote(2, 100).unwrap();
+        assert_eq!(proposal.options[1].votes, 100);
+        proposal.subtract_vote(2, 100).unwrap();
+        proposal.add_vote(3, 100).unwrap();
+        assert_eq!(proposal.options[1].votes, 0);
+        assert_eq!(proposal.options[2].votes, 100);
+
+        // pending votes do not count
+        proposal.add_vote(0, 100).unwrap();
+        assert_eq!(proposal.total_option_votes().unwrap(), 100);
+
+        assert!(proposal.add_vote(4, 100).is_err());
+        assert_eq!(proposal.for_votes, 0);
+    }
+
+    #[test]
+    fn test_multiple_choice_tie_defeated() {
+        let proposal = multiple_choice_proposal(&[50, 100, 100], 0);
+        assert_eq!(proposal.winning_option(), None);
+        assert_eq!(proposal.state(2).unwrap(), ProposalState::Defeated);
+        assert!(proposal.executable_instructions().is_empty());
+    }
+
+    #[test]
+    fn test_multiple_choice_no_votes_defeated() {
+        let proposal = multiple_choice_proposal(&[0, 0, 0], 0);
+        assert_eq!(proposal.winning_option(), None);
+        assert_eq!(proposal.state(2).unwrap(), ProposalState::Defeated);
+    }
+
+    proptest! {
+        #[test]
+        fn test_multiple_choice_succeeded(
+            winner in 0..MAX_PROPOSAL_OPTIONS,
+            num_options in 2..=MAX_PROPOSAL_OPTIONS,
+            (other_votes, winner_votes) in part_and_total(),
+        ) {
+            prop_assume!(winner < num_options);
+            let mut option_votes = vec![other_votes / num_options as u64; num_options];
+            option_votes[winner] = winner_votes;
+            let all_votes: u64 = option_votes.iter().sum();
+
+            let proposal = multiple_choice_proposal(&option_votes, all_votes);
+            assert_eq!(proposal.winning_option(), Some(winner));
+            assert_eq!(proposal.state(2).unwrap(), ProposalState::Succeeded);
+            assert_eq!(proposal.executable_instructions()[0].data, vec![winner as u8]);
+
+            let proposal = multiple_choice_proposal(&option_votes, all_votes + 1);
+            assert_eq!(proposal.state(2).unwrap(), ProposalState::Defeated);
+        }
+    }
+
+    fn extendable_proposal(for_votes: u64, against_votes: u64, quorum_votes: u64) -> Proposal {
+        Proposal {
+            activated_at: 1,
+            voting_ends_at: 1_000,
+            for_votes,
+            against_votes,
+            quorum_votes,
+            vote_extension_seconds: 100,
+            max_vote_extension_seconds: 150,
+            ..Proposal::default()
+        }
+    }
+
+    #[test]
+    fn test_vote_extension_first_quorum() {
+        let mut proposal = extendable_proposal(50, 0, 100);
+        let prev_outcome = proposal.outcome().unwrap();
+        proposal.add_vote(VoteSide::For.into(), 50).unwrap();
+        assert_eq!(proposal.vote_extension(950, prev_outcome).unwrap(), 50);
+        // too early for an extension
+        assert_eq!(proposal.vote_extension(899, prev_outcome).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_vote_extension_outcome_flip() {
+        let mut proposal = extendable_proposal(100, 50, 100);
+        let prev_outcome = proposal.outcome().unwrap();
+        proposal.add_vote(VoteSide::Against.into(), 100).unwrap();
+        assert!(!proposal.outcome().unwrap().is_passing);
+        assert_eq!(proposal.vote_extension(999, prev_outcome).unwrap(), 99);
+    }
+
+    #[test]
+    fn test_vote_extension_unchanged_outcome() {
+        let mut proposal = extendable_proposal(200, 50, 100);
+        let prev_outcome = proposal.outcome().unwrap();
+        proposal.add_vote(VoteSide::Against.into(), 100).unwrap();
+        assert_eq!(proposal.vote_extension(999, prev_outcome).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_vote_extension_cap() {
+        let mut proposal = extendable_proposal(100, 50, 100);
+        let prev_outcome = proposal.outcome().unwrap();
+        proposal.add_vote(VoteSide::Against.into(), 100).unwrap();
+        let extension = proposal.vote_extension(999, prev_outcome).unwrap();
+        proposal.extend_voting(extension).unwrap();
+        assert_eq!(proposal.voting_ends_at, 1_099);
+
+        let prev_outcome = proposal.outcome().unwrap();
+        proposal.add_vote(VoteSide::For.into(), 100).unwrap();
+        let extension = proposal.vote_extension(1_090, prev_outcome).unwrap();
+        assert_eq!(extension, 51);
+        proposal.extend_voting(extension).unwrap();
+        assert_eq!(proposal.voting_ends_at, 1_150);
+        assert_eq!(proposal.voting_extended_seconds, 150);
+
+        let prev_outcome = proposal.outcome().unwrap();
+        proposal.add_vote(VoteSide::Against.into(), 100).unwrap();
+        assert_eq!(proposal.vote_extension(1_149, prev_outcome).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_vote_extension_disabled() {
+        let mut proposal = Proposal {
+            vote_extension_seconds: 0,
+            ..extendable_proposal(50, 0, 100)
+        };
+        let prev_outcome = proposal.outcome().unwrap();
+        proposal.add_vote(VoteSide::For.into(), 50).unwrap();
+        assert_eq!(proposal.vote_extension(999, prev_outcome).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_emergency_params_at_least_as_strict() {
+        let governor_params = GovernanceParameters {
+            voting_delay: 100,
+            voting_period: 1_000,
+            quorum_votes: 500,
+            timelock_delay_seconds: 100,
+            ..GovernanceParameters::default()
+        };
+        let params = EmergencyParameters {
+            voting_delay: 0,
+            voting_period: 100,
+            quorum_votes: 1_000,
+            quorum_bps: 0,
+            approval_threshold_bps: 6_600,
+            timelock_delay_seconds: 0,
+        };
+        assert!(params.validate(&governor_params).is_ok());
+        assert!(EmergencyParameters::default()
+            .validate(&governor_params)
+            .is_ok());
+
+        for weaker in [
+            EmergencyParameters {
+                quorum_votes: 0,
+                ..params
+            },
+            EmergencyParameters {
+                quorum_votes: 499,
+                ..params
+            },
+            EmergencyParameters {
+                approval_threshold_bps: 5_000,
+                ..params
+            },
+            EmergencyParameters {
+                voting_period: 1_001,
+                ..params
+            },
+            EmergencyParameters {
+                timelock_delay_seconds: 101,
+                ..params
+            },
+        ] {
+            assert!(weaker.validate(&governor_params).is_err());
+        }
+    }
+
+    #[test]
+    fn test_quorum_votes_and_bps_both_required() {
+        let mut proposal = Proposal {
+            quorum_votes: 300,
+            quorum_bps: 1_000,
+            total_voting_power: 1_000,
+            ..Proposal::default()
+        };
+        assert_eq!(proposal.quorum_votes_required(), Some(300));
+        proposal.total_voting_power = 10_000;
+        assert_eq!(proposal.quorum_votes_required(), Some(1_000));
+    }
+
+    #[test]
+    fn test_quorum_bps_override_requires_governor_quorum_bps() {
+        let params = OverrideParameters {
+            quorum_bps: 1_000,
+            ..OverrideParameters::default()
+        };
+        assert!(params.validate(&GovernanceParameters::default()).is_err());
+        assert!(params
+            .validate(&GovernanceParameters {
+                quorum_bps: 500,
+                ..GovernanceParameters::default()
+            })
+            .is_ok());
+    }
+
+    #[test]
+    fn test_apply_parameter_override() {
+        let mut proposal = Proposal {
+            voting_period: 100,
+            quorum_votes: 1_000,
+            approval_threshold_bps: 6_000,
+            timelock_delay_seconds: 50,
+            ..Proposal::default()
+        };
+        proposal.apply_parameter_override(&OverrideParameters {
+            voting_period: 200,
+            quorum_votes: 500,
+            quorum_bps: 0,
+            approval_threshold_bps: 6_600,
+            timelock_delay_seconds: 0,
+        });
+        assert_eq!(proposal.voting_period, 200);
+        assert_eq!(proposal.quorum_votes, 1_000);
+        assert_eq!(proposal.quorum_bps, 0);
+        assert_eq!(proposal.approval_threshold_bps, 6_600);
+        assert_eq!(proposal.timelock_delay_seconds, 50);
+    }
+
+    #[test]
+    fn test_active_proposal_counter_limit() {
+        let mut counter = ActiveProposalCounter::default();
+        counter.increment(2).unwrap();
+        counter.increment(2).unwrap();
+        assert!(counter.increment(2).is_err());
+
+        counter.active_proposals = 2;
+        counter.decrement().unwrap();
+        counter.increment(2).unwrap();
+        assert_eq!(counter.active_proposals, 2);
+    }
 }
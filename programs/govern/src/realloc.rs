@@ -0,0 +1,35 @@
+//! Account reallocation helpers.
+
+use anchor_lang::prelude::*;
+
+/// Grows an account to `new_len` bytes, topping up its rent from the `payer`.
+/// Accounts are never shrunk.
+pub fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    if new_len <= account.data_len() {
+        return Ok(());
+    }
+
+    let rent_due = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+    if rent_due > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    account.realloc(new_len, true)?;
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 //! Struct definitions for accounts that hold state.
 
 use anchor_lang::{prelude::*, solana_program::pubkey::PUBKEY_BYTES};
+use vipers::{assert_keys_eq, invariant, unwrap_int, unwrap_opt};
 
 /// A Governor is the "DAO": it is the account that holds control over important protocol functions,
 /// including treasury, protocol parameters, and more.
@@ -25,11 +26,113 @@ pub struct Governor {
 
     /// Governance parameters.
     pub params: GovernanceParameters,
+
+    /// The guardian of the Governor, e.g. a security council.
+    /// The guardian may veto any proposal that has not yet been executed.
+    /// If set to [Pubkey::default], the Governor has no guardian.
+    pub guardian: Pubkey,
+
+    /// Mint of the tokens deposited when creating a [Proposal].
+    pub deposit_mint: Pubkey,
+    /// Number of tokens deposited when creating a [Proposal].
+    /// If zero, no deposit is required.
+    pub deposit_amount: u64,
+
+    /// Parameters of emergency proposals.
+    pub emergency_params: EmergencyParameters,
+
+    /// If set, proposals may only be created with the signature of this account,
+    /// e.g. a PDA of the electorate program which checks the proposer's voting power.
+    /// If set to [Pubkey::default], anyone may create proposals.
+    pub proposal_creation_gate: Pubkey,
+
+    /// [GovernanceParameters] staged by [crate::govern::set_governance_params],
+    /// which replace [Governor::params] once [Governor::pending_params_effective_at] is reached.
+    pub pending_params: GovernanceParameters,
+    /// When the [Governor::pending_params] may be applied. If zero, there are no pending parameters.
+    pub pending_params_effective_at: i64,
+    /// Electorate staged by [crate::govern::set_electorate],
+    /// which replaces [Governor::electorate] once [Governor::pending_electorate_effective_at] is reached.
+    pub pending_electorate: Pubkey,
+    /// When the [Governor::pending_electorate] may be applied. If zero, there is no pending electorate.
+    pub pending_electorate_effective_at: i64,
+    /// [EmergencyParameters] staged by [crate::govern::set_emergency_params],
+    /// which replace [Governor::emergency_params] once [Governor::pending_emergency_params_effective_at] is reached.
+    pub pending_emergency_params: EmergencyParameters,
+    /// When the [Governor::pending_emergency_params] may be applied. If zero, there are no pending parameters.
+    pub pending_emergency_params_effective_at: i64,
+    /// Gate staged by [crate::govern::set_proposal_creation_gate], which replaces
+    /// [Governor::proposal_creation_gate] once [Governor::pending_proposal_creation_gate_effective_at] is reached.
+    pub pending_proposal_creation_gate: Pubkey,
+    /// When the [Governor::pending_proposal_creation_gate] may be applied. If zero, there is no pending gate.
+    pub pending_proposal_creation_gate_effective_at: i64,
+    /// Deposit mint staged by [crate::govern::set_proposal_deposit].
+    pub pending_deposit_mint: Pubkey,
+    /// Deposit amount staged by [crate::govern::set_proposal_deposit].
+    pub pending_deposit_amount: u64,
+    /// When the [Governor::pending_deposit_mint] and [Governor::pending_deposit_amount] may be applied.
+    /// If zero, there is no pending deposit.
+    pub pending_deposit_effective_at: i64,
+    /// Guardian staged by [crate::govern::set_guardian], which replaces
+    /// [Governor::guardian] once [Governor::pending_guardian_effective_at] is reached.
+    pub pending_guardian: Pubkey,
+    /// When the [Governor::pending_guardian] may be applied. If zero, there is no pending guardian.
+    pub pending_guardian_effective_at: i64,
 }
 
 impl Governor {
     /// Number of bytes in a [Governor].
-    pub const LEN: usize = PUBKEY_BYTES + 1 + 8 + PUBKEY_BYTES * 2 + GovernanceParameters::LEN;
+    pub const LEN: usize = PUBKEY_BYTES
+        + 1
+        + 8
+        + PUBKEY_BYTES * 2
+        + GovernanceParameters::LEN
+        + PUBKEY_BYTES
+        + PUBKEY_BYTES
+        + 8
+        + EmergencyParameters::LEN
+        + PUBKEY_BYTES
+        + GovernanceParameters::LEN
+        + 8
+        + PUBKEY_BYTES
+        + 8
+        + EmergencyParameters::LEN
+        + 8
+        + PUBKEY_BYTES
+        + 8
+        + PUBKEY_BYTES
+        + 8
+        + 8
+        + PUBKEY_BYTES
+        + 8;
+
+    /// Number of bytes in a [Governor] created before [GovernanceParameters::grace_period_seconds] existed.
+    ///
+    /// Fields added since then must treat a zero value as the legacy behavior,
+    /// since [crate::govern::migrate_governor] zero-fills the new space.
+    pub const LEGACY_LEN: usize = PUBKEY_BYTES + 1 + 8 + PUBKEY_BYTES * 2 + 8 * 4;
+
+    /// Checks that the [Governor::proposal_creation_gate], if set, is the first of the
+    /// `remaining_accounts` and has signed. Returns the rest of the remaining accounts.
+    pub(crate) fn check_proposal_creation_gate<'a, 'info>(
+        &self,
+        remaining_accounts: &'a [AccountInfo<'info>],
+    ) -> Result<&'a [AccountInfo<'info>]> {
+        if self.proposal_creation_gate == Pubkey::default() {
+            return Ok(remaining_accounts);
+        }
+        let (gate, rest) = unwrap_opt!(
+            remaining_accounts.split_first(),
+            ProposalCreationGateNotSigned
+        );
+        assert_keys_eq!(
+            gate.key(),
+            self.proposal_creation_gate,
+            ProposalCreationGateNotSigned
+        );
+        invariant!(gate.is_signer, ProposalCreationGateNotSigned);
+        Ok(rest)
+    }
 }
 
 /// Governance parameters.
@@ -43,14 +146,160 @@ pub struct GovernanceParameters {
     pub quorum_votes: u64,
     /// The timelock delay of the DAO's created proposals.
     pub timelock_delay_seconds: i64,
+    /// The number of seconds after voting ends during which a succeeded proposal may be queued.
+    /// If zero, proposals never expire.
+    pub grace_period_seconds: u64,
+    /// The minimum share of [VoteSide::For] votes out of all [VoteSide::For] and [VoteSide::Against] votes
+    /// required for a proposal to succeed, in basis points.
+    /// If zero, a proposal succeeds if there are more [VoteSide::For] votes than [VoteSide::Against] votes.
+    ///
+    /// [VoteSide::For]: crate::VoteSide::For
+    /// [VoteSide::Against]: crate::VoteSide::Against
+    pub approval_threshold_bps: u16,
+    /// The number of votes required for a quorum, in basis points of the total voting power
+    /// reported by the electorate when the proposal is activated.
+    /// The electorate must support [crate::govern::activate_proposal_with_voting_power];
+    /// for example, the locked voter requires a `LockerHistory`.
+    /// If nonzero, both this and [GovernanceParameters::quorum_votes] must be met.
+    pub quorum_bps: u16,
+    /// The number of seconds that voting is extended by if a vote within the final
+    /// `vote_extension_seconds` of voting causes a proposal to first reach quorum or changes its outcome.
+    /// If zero, voting is never extended.
+    pub vote_extension_seconds: u64,
+    /// The maximum total number of seconds that voting on a proposal may be extended by.
+    pub max_vote_extension_seconds: u64,
+    /// If true, a proposal may be queued before voting ends once its outcome is decided;
+    /// that is, once it would succeed even if all of the remaining voting power voted against it.
+    /// This requires the electorate to report the total voting power when activating proposals,
+    /// and votes on such proposals are final once cast.
+    /// The locked voter only supports this with snapshot voting, since otherwise voting power
+    /// may grow after the proposal is activated.
+    pub early_queue_enabled: bool,
+    /// The maximum number of proposals that a single proposer may have active at once.
+    /// This is tracked by each proposer's [ActiveProposalCounter].
+    /// If zero, there is no limit.
+    pub max_active_proposals_per_proposer: u64,
+    /// The delay, in seconds, before changes to the [Governor] may be applied via
+    /// [crate::govern::apply_pending_params]. This covers [crate::govern::set_governance_params],
+    /// [crate::govern::set_electorate], [crate::govern::set_emergency_params],
+    /// [crate::govern::set_proposal_creation_gate], [crate::govern::set_proposal_deposit]
+    /// and [crate::govern::set_guardian]. Changes to an [OptimisticConfig] or a [ParameterOverride]
+    /// are delayed likewise, and applied via [crate::govern::apply_pending_optimistic_config]
+    /// and [crate::govern::apply_pending_parameter_override].
+    /// If zero, changes take effect immediately.
+    pub params_change_delay_seconds: u64,
 }
 
 impl GovernanceParameters {
     /// Number of bytes in a [GovernanceParameters].
-    pub const LEN: usize = 8 * 4;
+    pub const LEN: usize = 8 * 5 + 2 + 2 + 8 * 2 + 1 + 8 * 2;
+
+    /// Validates the [GovernanceParameters].
+    pub fn validate(&self) -> Result<()> {
+        invariant!(
+            self.timelock_delay_seconds >= 0,
+            "timelock delay must be at least 0 seconds"
+        );
+        invariant!(
+            u64::from(self.approval_threshold_bps) <= BPS_DENOMINATOR,
+            InvalidApprovalThreshold
+        );
+        invariant!(
+            u64::from(self.quorum_bps) <= BPS_DENOMINATOR,
+            InvalidQuorumBps
+        );
+        invariant!(
+            self.params_change_delay_seconds <= i64::MAX as u64,
+            "params change delay is too large"
+        );
+        Ok(())
+    }
+}
+
+/// Denominator of all basis point values.
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Parameters of emergency proposals, which trade shorter periods for a higher quorum and approval threshold.
+/// Emergency proposals may be queued as soon as they pass, even before voting ends.
+/// See [GovernanceParameters] for a description of each parameter.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct EmergencyParameters {
+    /// The delay before voting on an emergency proposal may begin, in seconds.
+    pub voting_delay: u64,
+    /// The duration of voting on an emergency proposal, in seconds.
+    pub voting_period: u64,
+    /// The number of votes required for a quorum.
+    pub quorum_votes: u64,
+    /// The number of votes required for a quorum, in basis points of the total voting power.
+    pub quorum_bps: u16,
+    /// The minimum share of for votes required for an emergency proposal to succeed, in basis points.
+    /// If zero, emergency proposals are disabled.
+    pub approval_threshold_bps: u16,
+    /// The timelock delay of an emergency proposal's transaction once queued, in seconds.
+    pub timelock_delay_seconds: i64,
+}
+
+impl EmergencyParameters {
+    /// Number of bytes in a [EmergencyParameters].
+    pub const LEN: usize = 8 * 3 + 2 * 2 + 8;
+
+    /// Returns true if emergency proposals are enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.approval_threshold_bps > 0
+    }
+
+    /// Validates the [EmergencyParameters] of a [Governor] with the given [GovernanceParameters].
+    ///
+    /// If emergency proposals are enabled, they must have a nonzero quorum, a quorum and approval threshold
+    /// at least as high as those of the [GovernanceParameters], and periods no longer than theirs.
+    pub fn validate(&self, governor_params: &GovernanceParameters) -> Result<()> {
+        invariant!(
+            self.timelock_delay_seconds >= 0,
+            "timelock delay must be at least 0 seconds"
+        );
+        invariant!(
+            u64::from(self.approval_threshold_bps) <= BPS_DENOMINATOR,
+            InvalidApprovalThreshold
+        );
+        invariant!(
+            u64::from(self.quorum_bps) <= BPS_DENOMINATOR,
+            InvalidQuorumBps
+        );
+        if !self.is_enabled() {
+            return Ok(());
+        }
+
+        invariant!(
+            self.quorum_votes > 0 || self.quorum_bps > 0,
+            InvalidEmergencyParameters
+        );
+        invariant!(
+            self.quorum_votes >= governor_params.quorum_votes
+                && self.quorum_bps >= governor_params.quorum_bps,
+            InvalidEmergencyParameters
+        );
+        // a threshold above half is at least as strict as a simple majority
+        let min_approval_threshold_bps = governor_params
+            .approval_threshold_bps
+            .max((BPS_DENOMINATOR / 2 + 1) as u16);
+        invariant!(
+            self.approval_threshold_bps >= min_approval_threshold_bps,
+            InvalidEmergencyParameters
+        );
+        invariant!(
+            self.voting_delay <= governor_params.voting_delay
+                && self.voting_period <= governor_params.voting_period
+                && self.timelock_delay_seconds <= governor_params.timelock_delay_seconds,
+            InvalidEmergencyParameters
+        );
+        Ok(())
+    }
 }
 
 /// A Proposal is a pending transaction that may or may not be executed by the DAO.
+///
+/// Fields added after [Proposal::instructions] are appended after the legacy layout.
+/// Proposals created before they existed must be migrated via [crate::govern::migrate_proposal].
 #[account]
 #[derive(Debug, Default)]
 pub struct Proposal {
@@ -91,17 +340,345 @@ pub struct Proposal {
     pub queued_transaction: Pubkey,
 
     /// The instructions associated with the proposal.
+    /// This is empty for multiple-choice proposals, which store instructions on each option.
     pub instructions: Vec<ProposalInstruction>,
+
+    /// The layout version of the proposal. Zero for proposals which have not been migrated.
+    pub version: u8,
+
+    /// The account which paid for the rent of the [Proposal].
+    /// Rent is refunded to this account when the [Proposal] is closed.
+    pub payer: Pubkey,
+    /// The kind of proposal. See [crate::ProposalKind].
+    pub kind: u8,
+    /// Whether this is an emergency proposal using the [Governor::emergency_params].
+    pub emergency: bool,
+    /// The [EmergencyParameters::voting_delay] of an emergency proposal, snapshotted when it was created.
+    /// Other proposals use the [Governor]'s current [GovernanceParameters::voting_delay].
+    pub emergency_voting_delay: u64,
+
+    /// The duration of voting on the proposal, in seconds.
+    pub voting_period: u64,
+    /// The timelock delay of the proposal's transaction once queued, in seconds.
+    pub timelock_delay_seconds: i64,
+    /// The number of seconds after voting ends during which the proposal may be queued.
+    /// If zero, the proposal never expires.
+    pub grace_period_seconds: u64,
+    /// The minimum share of for votes required for the proposal to succeed, in basis points.
+    /// See [GovernanceParameters::approval_threshold_bps].
+    pub approval_threshold_bps: u16,
+    /// The number of votes required for a quorum, in basis points of [Proposal::total_voting_power].
+    /// If nonzero, both this and [Proposal::quorum_votes] must be met.
+    pub quorum_bps: u16,
+    /// For [crate::ProposalKind::Optimistic] proposals, the number of [crate::VoteSide::Against] votes
+    /// above which the proposal is defeated.
+    pub veto_votes: u64,
+    /// The total voting power reported by the electorate when the proposal was activated.
+    /// Only used if [Proposal::quorum_bps] is non-zero.
+    pub total_voting_power: u64,
+    /// The number of seconds that voting is extended by after a late, outcome-changing vote.
+    /// See [GovernanceParameters::vote_extension_seconds].
+    pub vote_extension_seconds: u64,
+    /// The maximum total number of seconds that voting may be extended by.
+    pub max_vote_extension_seconds: u64,
+    /// The total number of seconds that voting has been extended by.
+    pub voting_extended_seconds: u64,
+    /// Whether the proposal may be queued once its outcome is decided.
+    /// See [GovernanceParameters::early_queue_enabled].
+    pub early_queue_enabled: bool,
+
+    /// The timestamp when the proposal was vetoed by the [Governor::guardian].
+    pub vetoed_at: i64,
+    /// The timestamp when the instructions of the proposal were last amended.
+    /// Zero if the instructions were never amended.
+    pub amended_at: i64,
+
+    /// Mint of the proposal deposit.
+    pub deposit_mint: Pubkey,
+    /// Number of tokens deposited by the proposer which have not yet been refunded or slashed.
+    pub deposit_amount: u64,
+
+    /// Whether all instructions have been added to the proposal.
+    /// Proposals created via [crate::govern::create_staged_proposal] are unsealed
+    /// until [crate::govern::seal_proposal] is called, and unsealed proposals cannot be activated.
+    pub sealed: bool,
+    /// Whether the proposal was counted in its proposer's [ActiveProposalCounter] upon activation
+    /// and has not yet been released via [crate::govern::release_active_proposal].
+    pub counted_as_active: bool,
+    /// If nonzero, the proposal only stores this commitment to its instructions rather than the instructions
+    /// themselves. This is the SHA-256 hash of the serialized instructions; see [crate::hash_instructions].
+    /// The instructions are revealed in [crate::govern::queue_committed_proposal].
+    pub instructions_hash: [u8; 32],
+
+    /// The options of a multiple-choice proposal.
+    /// This is empty for standard proposals.
+    pub options: Vec<ProposalOption>,
 }
 
 impl Proposal {
+    /// The current [Proposal::version].
+    pub const VERSION: u8 = 1;
+
     /// Space that the [Proposal] takes up.
     pub fn space(instructions: Vec<ProposalInstruction>) -> usize {
         4  // Anchor discriminator.
         + 4 // Vec discriminator
+            + 4 // Vec discriminator
             + std::mem::size_of::<Proposal>()
             + (instructions.iter().map(|ix| ix.space()).sum::<usize>())
     }
+
+    /// Space that a multiple-choice [Proposal] takes up.
+    pub fn multiple_choice_space(options: &[Vec<ProposalInstruction>]) -> usize {
+        Proposal::space(vec![])
+            + options
+                .iter()
+                .map(|instructions| ProposalOption::space(instructions))
+                .sum::<usize>()
+    }
+}
+
+/// An option of a multiple-choice [Proposal].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct ProposalOption {
+    /// Current number of votes for this option.
+    pub votes: u64,
+    /// The instructions to queue if this option wins.
+    pub instructions: Vec<ProposalInstruction>,
+}
+
+impl ProposalOption {
+    /// Space that a [ProposalOption] with the given instructions takes up.
+    pub fn space(instructions: &[ProposalInstruction]) -> usize {
+        8 // votes
+        + 4 // Vec discriminator
+            + (instructions.iter().map(|ix| ix.space()).sum::<usize>())
+    }
+}
+
+/// The layout of a [Proposal] before [Proposal::version] existed.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Default)]
+pub(crate) struct LegacyProposal {
+    pub(crate) governor: Pubkey,
+    pub(crate) index: u64,
+    pub(crate) bump: u8,
+    pub(crate) proposer: Pubkey,
+    pub(crate) quorum_votes: u64,
+    pub(crate) for_votes: u64,
+    pub(crate) against_votes: u64,
+    pub(crate) abstain_votes: u64,
+    pub(crate) canceled_at: i64,
+    pub(crate) created_at: i64,
+    pub(crate) activated_at: i64,
+    pub(crate) voting_ends_at: i64,
+    pub(crate) queued_at: i64,
+    pub(crate) queued_transaction: Pubkey,
+    pub(crate) instructions: Vec<ProposalInstruction>,
+}
+
+impl LegacyProposal {
+    /// Converts the [LegacyProposal] to a [Proposal] which behaves as it did before the migration.
+    ///
+    /// Legacy proposals read the voting period and timelock delay from the [Governor] when they were
+    /// activated and queued, so these are snapshotted from the [Governor]'s current parameters,
+    /// along with its grace period.
+    pub(crate) fn into_proposal(self, params: &GovernanceParameters) -> Proposal {
+        Proposal {
+            governor: self.governor,
+            index: self.index,
+            bump: self.bump,
+            proposer: self.proposer,
+            quorum_votes: self.quorum_votes,
+            for_votes: self.for_votes,
+            against_votes: self.against_votes,
+            abstain_votes: self.abstain_votes,
+            canceled_at: self.canceled_at,
+            created_at: self.created_at,
+            activated_at: self.activated_at,
+            voting_ends_at: self.voting_ends_at,
+            queued_at: self.queued_at,
+            queued_transaction: self.queued_transaction,
+            instructions: self.instructions,
+            version: Proposal::VERSION,
+            payer: self.proposer,
+            voting_period: params.voting_period,
+            timelock_delay_seconds: params.timelock_delay_seconds,
+            grace_period_seconds: params.grace_period_seconds,
+            sealed: true,
+            ..Proposal::default()
+        }
+    }
+}
+
+/// Governance parameters that apply to all proposals which invoke a given program.
+///
+/// When a [Proposal] is created, the strictest of the [Governor]'s parameters and
+/// the overrides of each invoked program are snapshotted onto the [Proposal].
+#[account]
+#[derive(Copy, Debug, Default)]
+pub struct ParameterOverride {
+    /// The [Governor].
+    pub governor: Pubkey,
+    /// The program whose invocations these parameters apply to.
+    pub program_id: Pubkey,
+    /// Bump seed
+    pub bump: u8,
+
+    /// The parameters.
+    pub params: OverrideParameters,
+
+    /// Parameters staged by [crate::govern::create_parameter_override] or [crate::govern::set_parameter_override],
+    /// which replace [ParameterOverride::params] once [ParameterOverride::pending_effective_at] is reached.
+    pub pending_params: OverrideParameters,
+    /// When the [ParameterOverride::pending_params] may be applied. If zero, there are no pending parameters.
+    pub pending_effective_at: i64,
+}
+
+impl ParameterOverride {
+    /// Number of bytes in a [ParameterOverride].
+    pub const LEN: usize = PUBKEY_BYTES * 2 + 1 + OverrideParameters::LEN * 2 + 8;
+
+    /// Returns true if the [ParameterOverride] has no effect and no pending changes,
+    /// so it may be removed via [crate::govern::remove_parameter_override].
+    pub fn is_cleared(&self) -> bool {
+        self.params == OverrideParameters::default() && self.pending_effective_at == 0
+    }
+}
+
+/// Parameters of a [ParameterOverride].
+/// See [GovernanceParameters] for a description of each parameter.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct OverrideParameters {
+    /// Minimum duration of voting, in seconds.
+    pub voting_period: u64,
+    /// Minimum number of votes required for a quorum.
+    pub quorum_votes: u64,
+    /// Minimum quorum, in basis points of the total voting power.
+    /// This may only be set if the [Governor] has a [GovernanceParameters::quorum_bps],
+    /// since otherwise its electorate may not report the total voting power.
+    pub quorum_bps: u16,
+    /// Minimum approval threshold, in basis points.
+    pub approval_threshold_bps: u16,
+    /// Minimum timelock delay, in seconds.
+    pub timelock_delay_seconds: i64,
+}
+
+impl OverrideParameters {
+    /// Number of bytes in a [OverrideParameters].
+    pub const LEN: usize = 8 * 2 + 2 * 2 + 8;
+
+    /// Validates the [OverrideParameters] of a [Governor] with the given [GovernanceParameters].
+    pub fn validate(&self, governor_params: &GovernanceParameters) -> Result<()> {
+        invariant!(
+            self.quorum_bps == 0 || governor_params.quorum_bps > 0,
+            QuorumBpsOverrideUnsupported
+        );
+        invariant!(
+            self.timelock_delay_seconds >= 0,
+            "timelock delay must be at least 0 seconds"
+        );
+        invariant!(
+            u64::from(self.approval_threshold_bps) <= BPS_DENOMINATOR,
+            InvalidApprovalThreshold
+        );
+        invariant!(
+            u64::from(self.quorum_bps) <= BPS_DENOMINATOR,
+            InvalidQuorumBps
+        );
+        Ok(())
+    }
+}
+
+/// Maximum number of [OptimisticConfig::proposers].
+pub const MAX_OPTIMISTIC_PROPOSERS: usize = 16;
+
+/// Configuration of the optimistic proposals of a [Governor].
+///
+/// Optimistic proposals succeed unless enough votes are cast against them,
+/// and may only be created by the [OptimisticConfig::proposers].
+#[account]
+#[derive(Debug, Default)]
+pub struct OptimisticConfig {
+    /// The [Governor].
+    pub governor: Pubkey,
+    /// Bump seed
+    pub bump: u8,
+
+    /// The parameters of optimistic proposals.
+    pub params: OptimisticParameters,
+    /// Accounts which may create optimistic proposals.
+    pub proposers: Vec<Pubkey>,
+
+    /// Parameters staged by [crate::govern::set_optimistic_config], which replace
+    /// [OptimisticConfig::params] once [OptimisticConfig::pending_effective_at] is reached.
+    pub pending_params: OptimisticParameters,
+    /// Proposers staged by [crate::govern::set_optimistic_config], which replace
+    /// [OptimisticConfig::proposers] once [OptimisticConfig::pending_effective_at] is reached.
+    pub pending_proposers: Vec<Pubkey>,
+    /// When the pending parameters and proposers may be applied. If zero, there are no pending changes.
+    pub pending_effective_at: i64,
+}
+
+impl OptimisticConfig {
+    /// Number of bytes in an [OptimisticConfig].
+    pub const LEN: usize = PUBKEY_BYTES
+        + 1
+        + (OptimisticParameters::LEN + 4 + PUBKEY_BYTES * MAX_OPTIMISTIC_PROPOSERS) * 2
+        + 8;
+}
+
+/// Parameters of an [OptimisticConfig].
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct OptimisticParameters {
+    /// The duration of voting on optimistic proposals, in seconds.
+    /// If zero, [GovernanceParameters::voting_period] is used.
+    pub voting_period: u64,
+    /// The number of [crate::VoteSide::Against] votes above which an optimistic proposal is defeated.
+    pub veto_votes: u64,
+}
+
+impl OptimisticParameters {
+    /// Number of bytes in a [OptimisticParameters].
+    pub const LEN: usize = 8 * 2;
+}
+
+/// Tracks the number of proposals a proposer has active under a [Governor].
+/// See [GovernanceParameters::max_active_proposals_per_proposer].
+#[account]
+#[derive(Debug, Default)]
+pub struct ActiveProposalCounter {
+    /// The [Governor].
+    pub governor: Pubkey,
+    /// The proposer.
+    pub proposer: Pubkey,
+    /// Bump seed
+    pub bump: u8,
+
+    /// The number of the proposer's proposals which have been activated
+    /// but not yet released.
+    pub active_proposals: u64,
+}
+
+impl ActiveProposalCounter {
+    /// Number of bytes in an [ActiveProposalCounter].
+    pub const LEN: usize = PUBKEY_BYTES * 2 + 1 + 8;
+
+    /// Records the activation of a proposal, failing if the proposer
+    /// would have more than `max_active_proposals` active proposals.
+    pub fn increment(&mut self, max_active_proposals: u64) -> Result<()> {
+        self.active_proposals = unwrap_int!(self.active_proposals.checked_add(1));
+        invariant!(
+            self.active_proposals <= max_active_proposals,
+            TooManyActiveProposals
+        );
+        Ok(())
+    }
+
+    /// Records that a proposal is no longer active.
+    pub fn decrement(&mut self) -> Result<()> {
+        self.active_proposals = unwrap_int!(self.active_proposals.checked_sub(1));
+        Ok(())
+    }
 }
 
 /// Metadata about a proposal.
@@ -114,6 +691,21 @@ pub struct ProposalMeta {
     pub title: String,
     /// Link to a description of the proposal.
     pub description_link: String,
+    /// The account which paid for the rent of the [ProposalMeta].
+    /// If [Pubkey::default], the [ProposalMeta] was created before this field existed,
+    /// and its rent is refunded to the [Proposal::proposer].
+    pub payer: Pubkey,
+}
+
+impl ProposalMeta {
+    /// Space that a [ProposalMeta] with the given title and description link takes up.
+    pub fn space(title: &str, description_link: &str) -> usize {
+        8 + std::mem::size_of::<ProposalMeta>()
+            + 4
+            + title.as_bytes().len()
+            + 4
+            + description_link.as_bytes().len()
+    }
 }
 
 /// A [Vote] is a vote made by a `voter` by an `electorate`.
@@ -131,11 +723,43 @@ pub struct Vote {
     pub side: u8,
     /// The number of votes this vote holds.
     pub weight: u64,
+
+    /// The account which paid for the rent of the [Vote].
+    pub payer: Pubkey,
 }
 
 impl Vote {
     /// Number of bytes in a [Vote].
-    pub const LEN: usize = PUBKEY_BYTES * 2 + 1 + 1 + 8;
+    pub const LEN: usize = PUBKEY_BYTES * 2 + 1 + 1 + 8 + PUBKEY_BYTES;
+
+    /// Number of bytes in a [Vote] created before [Vote::payer] existed.
+    /// Such votes must be migrated via [crate::govern::migrate_vote].
+    pub const LEGACY_LEN: usize = PUBKEY_BYTES * 2 + 1 + 1 + 8;
+}
+
+/// Maximum length in bytes of [VoteRationale::uri].
+pub const MAX_VOTE_RATIONALE_URI_LEN: usize = 200;
+
+/// A [VoteRationale] is an explanation published alongside a [Vote].
+#[account]
+#[derive(Debug, Default)]
+pub struct VoteRationale {
+    /// The [Vote].
+    pub vote: Pubkey,
+    /// Bump seed
+    pub bump: u8,
+    /// The account which paid for the rent of the [VoteRationale].
+    pub payer: Pubkey,
+
+    /// Hash of the rationale content. Zero if not provided.
+    pub content_hash: [u8; 32],
+    /// URI of the rationale content, or the rationale itself if it is short.
+    pub uri: String,
+}
+
+impl VoteRationale {
+    /// Number of bytes in a [VoteRationale].
+    pub const LEN: usize = PUBKEY_BYTES + 1 + PUBKEY_BYTES + 32 + 4 + MAX_VOTE_RATIONALE_URI_LEN;
 }
 
 /// Instruction.
@@ -153,7 +777,9 @@ impl ProposalInstruction {
     /// Space that a [ProposalInstruction] takes up.
     pub fn space(&self) -> usize {
         std::mem::size_of::<Pubkey>()
+            + 4 // Vec discriminator
             + (self.keys.len() as usize) * std::mem::size_of::<AccountMeta>()
+            + 4 // Vec discriminator
             + (self.data.len() as usize)
     }
 }
@@ -168,3 +794,65 @@ pub struct ProposalAccountMeta {
     /// True if the `pubkey` can be loaded as a read-write account.
     pub is_writable: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proposal_creation_gate() {
+        let gate = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let owner = Pubkey::default();
+        let (mut gate_lamports, mut other_lamports) = (0, 0);
+        let (mut gate_data, mut other_data) = (vec![], vec![]);
+        let mut gate_info = AccountInfo::new(
+            &gate,
+            true,
+            false,
+            &mut gate_lamports,
+            &mut gate_data,
+            &owner,
+            false,
+            0,
+        );
+        let mut other_info = AccountInfo::new(
+            &other,
+            true,
+            false,
+            &mut other_lamports,
+            &mut other_data,
+            &owner,
+            false,
+            0,
+        );
+
+        // no gate: all accounts are passed through
+        let mut governor = Governor::default();
+        let accounts = [other_info.clone()];
+        assert_eq!(
+            governor
+                .check_proposal_creation_gate(&accounts)
+                .unwrap()
+                .len(),
+            1
+        );
+
+        governor.proposal_creation_gate = gate;
+        // missing gate
+        assert!(governor.check_proposal_creation_gate(&[]).is_err());
+        // wrong gate
+        assert!(governor.check_proposal_creation_gate(&accounts).is_err());
+        // gate is consumed
+        let accounts = [gate_info.clone(), other_info.clone()];
+        let rest = governor.check_proposal_creation_gate(&accounts).unwrap();
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0].key(), other);
+
+        // gate did not sign
+        gate_info.is_signer = false;
+        other_info.is_signer = false;
+        let accounts = [gate_info, other_info];
+        assert!(governor.check_proposal_creation_gate(&accounts).is_err());
+    }
+}
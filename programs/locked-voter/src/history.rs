@@ -0,0 +1,426 @@
+//! Voting power history of a [Locker] and its [Escrow]s.
+
+use crate::*;
+
+/// The index of the [LockerHistory::slope_changes] entry for the week boundary at `timestamp`.
+fn slope_change_index(timestamp: i64) -> usize {
+    (timestamp.div_euclid(SECONDS_PER_WEEK) as usize) % MAX_SLOPE_CHANGE_WEEKS
+}
+
+/// The first week boundary at or after `timestamp`.
+fn ceil_week(timestamp: i64) -> Option<i64> {
+    timestamp
+        .checked_add(SECONDS_PER_WEEK - 1)?
+        .div_euclid(SECONDS_PER_WEEK)
+        .checked_mul(SECONDS_PER_WEEK)
+}
+
+/// Advances a [LockerPoint] to the timestamp `to`, applying the slope change at each week boundary crossed.
+///
+/// `take_slope_change` returns the slope change at a week boundary, and `on_slope_change` is
+/// called with the [LockerPoint] at each week boundary where the slope changed.
+fn advance_point(
+    mut point: LockerPoint,
+    to: i64,
+    mut take_slope_change: impl FnMut(i64) -> u64,
+    mut on_slope_change: impl FnMut(LockerPoint),
+) -> Option<LockerPoint> {
+    let mut week = point.timestamp.div_euclid(SECONDS_PER_WEEK) * SECONDS_PER_WEEK;
+    // every scheduled slope change is at most [MAX_SLOPE_CHANGE_WEEKS] ahead of the point
+    for _ in 0..MAX_SLOPE_CHANGE_WEEKS {
+        week = week.checked_add(SECONDS_PER_WEEK)?;
+        let timestamp = week.min(to);
+        point.bias = point.bias.checked_sub(
+            u128::from(point.slope)
+                .checked_mul(timestamp.checked_sub(point.timestamp)?.try_into().ok()?)?,
+        )?;
+        point.timestamp = timestamp;
+        if week > to {
+            return Some(point);
+        }
+
+        let slope_change = take_slope_change(week);
+        if slope_change > 0 {
+            point.slope = point.slope.checked_sub(slope_change)?;
+            on_slope_change(point);
+        }
+        if week == to {
+            return Some(point);
+        }
+    }
+
+    // all tracked escrows have ended
+    if point.slope != 0 || point.bias != 0 {
+        return None;
+    }
+    point.timestamp = to;
+    Some(point)
+}
+
+impl LockerHistory {
+    /// Initializes the [LockerHistory] with zero voting power.
+    pub fn init(&mut self, locker: Pubkey, bump: u8, timestamp: i64) {
+        self.locker = locker;
+        self.bump = bump;
+        self.points = vec![LockerPoint {
+            bias: 0,
+            slope: 0,
+            timestamp,
+        }];
+        self.slope_changes = vec![0; MAX_SLOPE_CHANGE_WEEKS];
+    }
+
+    /// The most recent [LockerPoint].
+    pub fn last_point(&self) -> Option<LockerPoint> {
+        self.points.last().copied()
+    }
+
+    /// Records a [LockerPoint], replacing the latest point if it has the same timestamp.
+    fn push_point(points: &mut Vec<LockerPoint>, point: LockerPoint) {
+        if let Some(last) = points.last_mut() {
+            if last.timestamp == point.timestamp {
+                *last = point;
+                return;
+            }
+        }
+        if points.len() >= MAX_LOCKER_POINTS {
+            points.remove(0);
+        }
+        points.push(point);
+    }
+
+    /// Advances the history to `now`, applying all slope changes up to and including `now`.
+    pub fn checkpoint(&mut self, now: i64) -> Result<()> {
+        let last_point = unwrap_opt!(self.last_point(), "locker history not initialized");
+        invariant!(now >= last_point.timestamp, "cannot checkpoint in the past");
+
+        let slope_changes = &mut self.slope_changes;
+        let points = &mut self.points;
+        let point = unwrap_int!(advance_point(
+            last_point,
+            now,
+            |week| std::mem::take(&mut slope_changes[slope_change_index(week)]),
+            |point| Self::push_point(points, point),
+        ));
+        Self::push_point(points, point);
+
+        Ok(())
+    }
+
+    /// Records a change in the lock of an [Escrow] at `now`, from `prev_amount` tokens ending at
+    /// `prev_escrow_ends_at` to `next_amount` tokens ending at `next_escrow_ends_at`.
+    pub fn record_escrow_change(
+        &mut self,
+        now: i64,
+        prev_amount: u64,
+        prev_escrow_ends_at: i64,
+        next_amount: u64,
+        next_escrow_ends_at: i64,
+    ) -> Result<()> {
+        self.checkpoint(now)?;
+
+        let slope_changes = &mut self.slope_changes;
+        let point = unwrap_opt!(self.points.last_mut(), "locker history not initialized");
+
+        let prev_ends_at = unwrap_int!(ceil_week(prev_escrow_ends_at));
+        if prev_amount > 0 && prev_ends_at > now {
+            let remaining_seconds = unwrap_int!(prev_ends_at.checked_sub(now));
+            point.bias = unwrap_int!(point.bias.checked_sub(unwrap_int!(
+                u128::from(prev_amount).checked_mul(remaining_seconds as u128)
+            )));
+            point.slope = unwrap_int!(point.slope.checked_sub(prev_amount));
+            let slope_change = &mut slope_changes[slope_change_index(prev_ends_at)];
+            *slope_change = unwrap_int!(slope_change.checked_sub(prev_amount));
+        }
+
+        let next_ends_at = unwrap_int!(ceil_week(next_escrow_ends_at));
+        if next_amount > 0 && next_ends_at > now {
+            let weeks_ahead = unwrap_int!(next_ends_at
+                .div_euclid(SECONDS_PER_WEEK)
+                .checked_sub(now.div_euclid(SECONDS_PER_WEEK)));
+            invariant!(
+                weeks_ahead < MAX_SLOPE_CHANGE_WEEKS as i64,
+                LockTooLongForHistory
+            );
+            let remaining_seconds = unwrap_int!(next_ends_at.checked_sub(now));
+            point.bias = unwrap_int!(point.bias.checked_add(unwrap_int!(
+                u128::from(next_amount).checked_mul(remaining_seconds as u128)
+            )));
+            point.slope = unwrap_int!(point.slope.checked_add(next_amount));
+            let slope_change = &mut slope_changes[slope_change_index(next_ends_at)];
+            *slope_change = unwrap_int!(slope_change.checked_add(next_amount));
+        }
+
+        Ok(())
+    }
+
+    /// Gets the [LockerPoint] at the given time.
+    ///
+    /// Times after the latest point are computed from the scheduled slope changes.
+    /// Returns [None] if the time is before the oldest recorded point.
+    pub fn point_at(&self, timestamp: i64) -> Option<LockerPoint> {
+        let last_point = self.last_point()?;
+        if timestamp >= last_point.timestamp {
+            return advance_point(
+                last_point,
+                timestamp,
+                |week| self.slope_changes[slope_change_index(week)],
+                |_| {},
+            );
+        }
+
+        // a point is recorded at every slope change, so the slope is constant
+        // between the preceding point and the given time.
+        let point = self
+            .points
+            .iter()
+            .rev()
+            .find(|point| point.timestamp <= timestamp)?;
+        let elapsed_seconds: u128 = timestamp.checked_sub(point.timestamp)?.try_into().ok()?;
+        Some(LockerPoint {
+            bias: point
+                .bias
+                .checked_sub(u128::from(point.slope).checked_mul(elapsed_seconds)?)?,
+            slope: point.slope,
+            timestamp,
+        })
+    }
+
+    /// Gets the total voting power of all [Escrow]s at the given time.
+    pub fn total_voting_power_at(&self, locker: &LockerParams, timestamp: i64) -> Option<u64> {
+        locker.calculate_total_voter_power(&self.point_at(timestamp)?)
+    }
+}
+
+/// If [Locker::history_enabled] is set, loads the [LockerHistory] of the [Locker],
+/// which must be the last of the `remaining_accounts`.
+///
+/// Returns the [LockerHistory], if any, and the other remaining accounts.
+pub fn split_locker_history<'a, 'info>(
+    locker: &Account<'info, Locker>,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<(
+    Option<Account<'info, LockerHistory>>,
+    &'a [AccountInfo<'info>],
+)> {
+    if !locker.history_enabled {
+        return Ok((None, remaining_accounts));
+    }
+    let (locker_history_info, rest) =
+        unwrap_opt!(remaining_accounts.split_last(), MustProvideLockerHistory);
+    let locker_history = Account::<LockerHistory>::try_from(locker_history_info)?;
+    assert_keys_eq!(
+        locker_history.locker,
+        locker.key(),
+        MustProvideLockerHistory
+    );
+    Ok((Some(locker_history), rest))
+}
+
+/// If [LockerParams::snapshot_voting_enabled] is set, records the `lock` of an [Escrow] at `now`
+/// in its [EscrowHistory], whose address must be the last of the `remaining_accounts`.
+/// Nothing is recorded if the [EscrowHistory] has not been created.
+///
+/// Returns the other remaining accounts.
+pub fn record_escrow_history<'a, 'info>(
+    locker: &Account<'info, Locker>,
+    escrow: Pubkey,
+    lock: &Escrow,
+    remaining_accounts: &'a [AccountInfo<'info>],
+    now: i64,
+) -> Result<&'a [AccountInfo<'info>]> {
+    if !locker.params.snapshot_voting_enabled {
+        return Ok(remaining_accounts);
+    }
+    let (escrow_history_info, rest) =
+        unwrap_opt!(remaining_accounts.split_last(), MustProvideEscrowHistory);
+    let (escrow_history_key, _) =
+        Pubkey::find_program_address(&[b"EscrowHistory".as_ref(), escrow.as_ref()], &crate::ID);
+    assert_keys_eq!(
+        escrow_history_info.key(),
+        escrow_history_key,
+        MustProvideEscrowHistory
+    );
+    if escrow_history_info.data_is_empty() {
+        return Ok(rest);
+    }
+
+    let mut escrow_history = Account::<EscrowHistory>::try_from(escrow_history_info)?;
+    if escrow_history.record(lock, now, locker.snapshot_retention_seconds)? {
+        escrow_history.exit(&crate::ID)?;
+    }
+    Ok(rest)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const DAY: i64 = 24 * 60 * 60;
+    const MAX_TIME: i64 = 4 * 365 * DAY;
+    const START_TIME: i64 = 1635379200;
+
+    fn locker_params() -> LockerParams {
+        LockerParams {
+            max_stake_duration: MAX_TIME as u64,
+            max_stake_vote_multiplier: 10,
+            ..LockerParams::default()
+        }
+    }
+
+    fn new_history() -> LockerHistory {
+        let mut history = LockerHistory::default();
+        history.init(Pubkey::default(), 0, START_TIME);
+        history
+    }
+
+    #[test]
+    fn test_week_aligned_locks_are_exact() {
+        let params = &locker_params();
+        let history = &mut new_history();
+        let week_start = ceil_week(START_TIME).unwrap();
+
+        let alice = Escrow {
+            amount: 1_000_000,
+            escrow_started_at: week_start,
+            escrow_ends_at: week_start + 4 * SECONDS_PER_WEEK,
+            ..Escrow::default()
+        };
+        let bob = Escrow {
+            amount: 3_000_000,
+            escrow_started_at: week_start,
+            escrow_ends_at: week_start + 10 * SECONDS_PER_WEEK,
+            ..Escrow::default()
+        };
+        history
+            .record_escrow_change(week_start, 0, 0, alice.amount, alice.escrow_ends_at)
+            .unwrap();
+        history
+            .record_escrow_change(week_start, 0, 0, bob.amount, bob.escrow_ends_at)
+            .unwrap();
+
+        for days in 0..(12 * 7) {
+            let ts = week_start + days * DAY;
+            let expected = params.calculate_voter_power(&alice, ts).unwrap()
+                + params.calculate_voter_power(&bob, ts).unwrap();
+            let actual = history.total_voting_power_at(params, ts).unwrap();
+            assert!(actual.abs_diff(expected) <= 1, "{} vs {}", actual, expected);
+        }
+
+        // past points are preserved once checkpointed
+        let ts = week_start + 6 * SECONDS_PER_WEEK + DAY;
+        history
+            .checkpoint(week_start + 12 * SECONDS_PER_WEEK)
+            .unwrap();
+        assert_eq!(
+            history.total_voting_power_at(params, ts).unwrap(),
+            params.calculate_voter_power(&bob, ts).unwrap()
+        );
+        assert_eq!(
+            history
+                .total_voting_power_at(params, week_start + 12 * SECONDS_PER_WEEK)
+                .unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_exit_removes_remaining_power() {
+        let params = &locker_params();
+        let history = &mut new_history();
+        let ends_at = START_TIME + DAY;
+        history
+            .record_escrow_change(START_TIME, 0, 0, 1_000, ends_at)
+            .unwrap();
+        assert!(history.total_voting_power_at(params, ends_at + 1).unwrap() > 0);
+
+        history
+            .record_escrow_change(ends_at + 1, 1_000, ends_at, 0, 0)
+            .unwrap();
+        assert_eq!(history.total_voting_power_at(params, ends_at + 1), Some(0));
+        assert_eq!(
+            history.slope_changes[slope_change_index(ceil_week(ends_at).unwrap())],
+            0
+        );
+    }
+
+    #[test]
+    fn test_lock_too_long_for_history() {
+        let history = &mut new_history();
+        let ends_at = START_TIME + MAX_SLOPE_CHANGE_WEEKS as i64 * SECONDS_PER_WEEK;
+        assert!(history
+            .record_escrow_change(START_TIME, 0, 0, 1_000, ends_at)
+            .is_err());
+    }
+
+    #[test]
+    fn test_max_history_stake_duration_is_tracked() {
+        let history = &mut new_history();
+        // the worst case is a lock made just after a week boundary which does not end on one
+        let now = ceil_week(START_TIME).unwrap() + 1;
+        let ends_at = now + MAX_HISTORY_STAKE_DURATION as i64;
+        history
+            .record_escrow_change(now, 0, 0, 1_000, ends_at)
+            .unwrap();
+        assert!(history
+            .record_escrow_change(now, 0, 0, 1_000, ends_at + SECONDS_PER_WEEK)
+            .is_err());
+
+        let params = LockerParams {
+            max_stake_duration: MAX_HISTORY_STAKE_DURATION,
+            ..locker_params()
+        };
+        params.validate_history().unwrap();
+        assert!(LockerParams {
+            max_stake_duration: MAX_HISTORY_STAKE_DURATION + 1,
+            ..params
+        }
+        .validate_history()
+        .is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_total_voting_power_bounds(
+            locks in prop::collection::vec((1..1_000_000_000u64, 0..30 * DAY, 1..MAX_TIME), 1..10),
+            elapsed in 0..(MAX_TIME + 60 * DAY),
+        ) {
+            let params = &locker_params();
+            let history = &mut new_history();
+            let mut escrows = vec![];
+            for (amount, offset, duration) in locks {
+                let now = START_TIME + offset;
+                let escrow = Escrow {
+                    amount,
+                    escrow_started_at: now,
+                    escrow_ends_at: now + duration,
+                    ..Escrow::default()
+                };
+                escrows.push((now, escrow));
+            }
+            escrows.sort_by_key(|(now, _)| *now);
+            for (now, escrow) in escrows.iter() {
+                history
+                    .record_escrow_change(*now, 0, 0, escrow.amount, escrow.escrow_ends_at)
+                    .unwrap();
+            }
+
+            let ts = START_TIME + 30 * DAY + elapsed;
+            let actual = history.total_voting_power_at(params, ts).unwrap();
+            let expected: u64 = escrows
+                .iter()
+                .map(|(_, escrow)| params.calculate_voter_power(escrow, ts).unwrap())
+                .sum();
+            let max_overestimate: u64 = escrows
+                .iter()
+                .map(|(_, escrow)| {
+                    (escrow.amount as u128 * 10 * SECONDS_PER_WEEK as u128 / MAX_TIME as u128) as u64 + 1
+                })
+                .sum();
+            prop_assert!(actual + escrows.len() as u64 >= expected);
+            prop_assert!(actual <= expected + max_overestimate);
+        }
+    }
+}
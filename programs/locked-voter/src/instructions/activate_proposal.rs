@@ -1,9 +1,11 @@
 use crate::*;
+use num_traits::ToPrimitive;
 
 /// Accounts for [locked_voter::activate_proposal].
 #[derive(Accounts)]
 pub struct ActivateProposal<'info> {
     /// The [Locker].
+    #[account(mut)]
     pub locker: Account<'info, Locker>,
     /// The [Governor].
     pub governor: Account<'info, Governor>,
@@ -20,20 +22,74 @@ pub struct ActivateProposal<'info> {
 
 impl<'info> ActivateProposal<'info> {
     /// Activates the proposal.
-    pub fn activate_proposal(&mut self) -> Result<()> {
+    ///
+    /// If [Locker::history_enabled] is set, the [LockerHistory] must be the last of the
+    /// `remaining_accounts`, and the total voting power is reported via
+    /// [govern::activate_proposal_with_voting_power]. The other remaining accounts
+    /// are passed through to [govern].
+    pub fn activate_proposal(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        if self.locker.params.snapshot_voting_enabled {
+            // escrow checkpoints must outlive voting on the proposal
+            let voting_period = unwrap_int!(self
+                .proposal
+                .voting_period
+                .checked_add(self.proposal.max_vote_extension_seconds));
+            let locker = &mut self.locker;
+            locker.snapshot_retention_seconds =
+                locker.snapshot_retention_seconds.max(voting_period);
+        }
+
         let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+        let (locker_history, remaining_accounts) =
+            split_locker_history(&self.locker, remaining_accounts)?;
+        let cpi_ctx = CpiContext::new(
+            self.govern_program.to_account_info(),
+            self.to_activate_proposal_accounts(),
+        )
+        .with_signer(seeds)
+        .with_remaining_accounts(remaining_accounts.to_vec());
 
-        govern::cpi::activate_proposal(
-            CpiContext::new(
-                self.govern_program.to_account_info(),
-                self.to_activate_proposal_accounts(),
-            )
-            .with_signer(seeds),
-        )?;
+        match locker_history {
+            Some(locker_history) => {
+                // otherwise, voting power may exceed the reported total after activation
+                invariant!(
+                    !self.proposal.early_queue_enabled
+                        || self.locker.params.snapshot_voting_enabled,
+                    EarlyQueueRequiresSnapshotVoting
+                );
+                let total_voting_power = unwrap_opt!(
+                    locker_history.total_voting_power_at(
+                        &self.locker.params,
+                        self.total_voting_power_timestamp()?
+                    ),
+                    "invalid total voting power"
+                );
+                govern::cpi::activate_proposal_with_voting_power(cpi_ctx, total_voting_power)?;
+            }
+            None => {
+                // the total voting power can only be reported from the locker history
+                invariant!(self.proposal.quorum_bps == 0, MustProvideLockerHistory);
+                govern::cpi::activate_proposal(cpi_ctx)?
+            }
+        }
 
         Ok(())
     }
 
+    /// The time at which votes are weighted: the activation of the proposal in snapshot mode,
+    /// otherwise the end of voting.
+    fn total_voting_power_timestamp(&self) -> Result<i64> {
+        let now = Clock::get()?.unix_timestamp;
+        if self.locker.params.snapshot_voting_enabled {
+            return Ok(now);
+        }
+        Ok(unwrap_int!(self
+            .proposal
+            .voting_period
+            .to_i64()
+            .and_then(|voting_period| now.checked_add(voting_period))))
+    }
+
     /// Conversion.
     fn to_activate_proposal_accounts(&self) -> govern::cpi::accounts::ActivateProposal<'info> {
         govern::cpi::accounts::ActivateProposal {
@@ -0,0 +1,77 @@
+//! Handler for [locked_voter::approve_escrow_extender].
+
+use crate::*;
+
+/// Accounts for [locked_voter::approve_escrow_extender].
+#[derive(Accounts)]
+pub struct ApproveEscrowExtender<'info> {
+    /// The [Escrow].
+    pub escrow: Account<'info, Escrow>,
+    /// [EscrowExtender].
+    #[account(
+        init,
+        seeds = [
+            b"EscrowExtender".as_ref(),
+            escrow.key().to_bytes().as_ref(),
+            extender.key().to_bytes().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + EscrowExtender::LEN
+    )]
+    pub escrow_extender: Account<'info, EscrowExtender>,
+
+    /// CHECK: The account to authorize to extend the lock of the [Escrow].
+    pub extender: UncheckedAccount<'info>,
+
+    /// Owner of the [Escrow].
+    pub escrow_owner: Signer<'info>,
+
+    /// Payer of the initialization.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ApproveEscrowExtender<'info> {
+    /// Creates a new [EscrowExtender].
+    pub fn approve_escrow_extender(&mut self, bump: u8, max_escrow_ends_at: i64) -> Result<()> {
+        let escrow_extender = &mut self.escrow_extender;
+        escrow_extender.escrow = self.escrow.key();
+        escrow_extender.extender = self.extender.key();
+        escrow_extender.bump = bump;
+        escrow_extender.max_escrow_ends_at = max_escrow_ends_at;
+
+        emit!(ApproveEscrowExtenderEvent {
+            escrow: escrow_extender.escrow,
+            extender: escrow_extender.extender,
+            max_escrow_ends_at,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for ApproveEscrowExtender<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.escrow.owner, self.escrow_owner);
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [locked_voter::approve_escrow_extender].
+pub struct ApproveEscrowExtenderEvent {
+    /// The [Escrow].
+    #[index]
+    pub escrow: Pubkey,
+    /// The account authorized to extend the lock of the [Escrow].
+    pub extender: Pubkey,
+    /// The latest [Escrow::escrow_ends_at] the extender may extend the lock to.
+    pub max_escrow_ends_at: i64,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
@@ -25,8 +25,12 @@ pub struct CastVote<'info> {
 }
 
 impl<'info> CastVote<'info> {
-    pub fn cast_vote(&mut self, side: u8) -> Result<()> {
-        let voting_power = self.future_voting_power()?;
+    pub fn cast_vote(&mut self, remaining_accounts: &[AccountInfo<'info>], side: u8) -> Result<()> {
+        let voting_power = if self.locker.params.snapshot_voting_enabled {
+            self.snapshot_voting_power(remaining_accounts)?
+        } else {
+            self.future_voting_power()?
+        };
 
         // zero votes should short circuit.
         if voting_power == 0 {
@@ -49,6 +53,18 @@ impl<'info> CastVote<'info> {
         Ok(())
     }
 
+    /// The voting power of the escrow at the time the proposal was activated,
+    /// according to its [EscrowHistory], which must be the first remaining account.
+    fn snapshot_voting_power(&self, remaining_accounts: &[AccountInfo<'info>]) -> Result<u64> {
+        let escrow_history_info = unwrap_opt!(remaining_accounts.first(), MustProvideEscrowHistory);
+        let escrow_history = Account::<EscrowHistory>::try_from(escrow_history_info)?;
+        assert_keys_eq!(escrow_history.escrow, self.escrow, MustProvideEscrowHistory);
+        Ok(unwrap_int!(escrow_history.voting_power_at_time(
+            &self.locker.params,
+            self.proposal.activated_at
+        )))
+    }
+
     /// The voting power of the escrow at the time the proposal's voting ends.
     fn future_voting_power(&self) -> Result<u64> {
         Ok(unwrap_int!(self.escrow.voting_power_at_time(
@@ -0,0 +1,61 @@
+use crate::*;
+
+/// Accounts for [locked_voter::checkpoint_escrow].
+#[derive(Accounts)]
+pub struct CheckpointEscrow<'info> {
+    /// [Locker].
+    pub locker: Account<'info, Locker>,
+    /// [Escrow].
+    pub escrow: Account<'info, Escrow>,
+    /// [EscrowHistory] of the [Escrow].
+    #[account(mut)]
+    pub escrow_history: Account<'info, EscrowHistory>,
+}
+
+impl<'info> CheckpointEscrow<'info> {
+    /// Records the current lock of the [Escrow] in its [EscrowHistory].
+    pub fn checkpoint_escrow(&mut self) -> Result<()> {
+        let timestamp = Clock::get()?.unix_timestamp;
+        if !self.escrow_history.record(
+            &self.escrow,
+            timestamp,
+            self.locker.snapshot_retention_seconds,
+        )? {
+            return Ok(());
+        }
+
+        emit!(EscrowCheckpointEvent {
+            escrow: self.escrow.key(),
+            amount: self.escrow.amount,
+            escrow_started_at: self.escrow.escrow_started_at,
+            escrow_ends_at: self.escrow.escrow_ends_at,
+            timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for CheckpointEscrow<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.escrow.locker, self.locker);
+        assert_keys_eq!(self.escrow_history.escrow, self.escrow);
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [locked_voter::checkpoint_escrow].
+pub struct EscrowCheckpointEvent {
+    /// The [Escrow].
+    #[index]
+    pub escrow: Pubkey,
+    /// [Escrow::amount].
+    pub amount: u64,
+    /// [Escrow::escrow_started_at].
+    pub escrow_started_at: i64,
+    /// [Escrow::escrow_ends_at].
+    pub escrow_ends_at: i64,
+    /// Timestamp of the checkpoint.
+    pub timestamp: i64,
+}
@@ -0,0 +1,25 @@
+use crate::*;
+
+/// Accounts for [locked_voter::checkpoint_locker].
+#[derive(Accounts)]
+pub struct CheckpointLocker<'info> {
+    /// [Locker].
+    pub locker: Account<'info, Locker>,
+    /// [LockerHistory] of the [Locker].
+    #[account(mut)]
+    pub locker_history: Account<'info, LockerHistory>,
+}
+
+impl<'info> CheckpointLocker<'info> {
+    /// Advances the [LockerHistory] to the current time.
+    pub fn checkpoint_locker(&mut self) -> Result<()> {
+        self.locker_history.checkpoint(Clock::get()?.unix_timestamp)
+    }
+}
+
+impl<'info> Validate<'info> for CheckpointLocker<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.locker_history.locker, self.locker);
+        Ok(())
+    }
+}
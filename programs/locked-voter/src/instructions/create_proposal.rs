@@ -0,0 +1,185 @@
+use crate::*;
+use govern::ProposalInstruction;
+
+/// Accounts for [locked_voter::create_proposal] and the other proposal creation instructions.
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    /// The [Locker].
+    pub locker: Account<'info, Locker>,
+    /// The proposer's [Escrow].
+    pub escrow: Account<'info, Escrow>,
+    /// The [Escrow]'s owner, who becomes the proposer.
+    pub escrow_owner: Signer<'info>,
+
+    /// The [Governor].
+    #[account(mut)]
+    pub governor: Account<'info, Governor>,
+    /// The [Proposal], initialized by the [govern] program.
+    /// CHECK: Checked by the [govern] program.
+    #[account(mut)]
+    pub proposal: UncheckedAccount<'info>,
+    /// Payer of the [Proposal].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+    /// The [govern] program.
+    pub govern_program: Program<'info, govern::program::Govern>,
+}
+
+impl<'info> CreateProposal<'info> {
+    /// Creates a standard proposal, signing as the [Governor::proposal_creation_gate].
+    pub fn create_proposal(
+        &self,
+        remaining_accounts: &[AccountInfo<'info>],
+        bump: u8,
+        instructions: Vec<ProposalInstruction>,
+    ) -> Result<()> {
+        let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+        govern::cpi::create_proposal(
+            CpiContext::new(
+                self.govern_program.to_account_info(),
+                self.to_create_proposal_accounts(),
+            )
+            .with_signer(seeds)
+            .with_remaining_accounts(self.with_gate(remaining_accounts)),
+            bump,
+            instructions,
+        )
+    }
+
+    /// Creates an emergency proposal, signing as the [Governor::proposal_creation_gate].
+    pub fn create_emergency_proposal(
+        &self,
+        remaining_accounts: &[AccountInfo<'info>],
+        bump: u8,
+        instructions: Vec<ProposalInstruction>,
+    ) -> Result<()> {
+        let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+        govern::cpi::create_emergency_proposal(
+            CpiContext::new(
+                self.govern_program.to_account_info(),
+                self.to_create_proposal_accounts(),
+            )
+            .with_signer(seeds)
+            .with_remaining_accounts(self.with_gate(remaining_accounts)),
+            bump,
+            instructions,
+        )
+    }
+
+    /// Creates a staged proposal, signing as the [Governor::proposal_creation_gate].
+    pub fn create_staged_proposal(
+        &self,
+        remaining_accounts: &[AccountInfo<'info>],
+        bump: u8,
+        capacity: u32,
+    ) -> Result<()> {
+        let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+        let accounts = govern::cpi::accounts::CreateStagedProposal {
+            governor: self.governor.to_account_info(),
+            proposal: self.proposal.to_account_info(),
+            proposer: self.escrow_owner.to_account_info(),
+            payer: self.payer.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        };
+        govern::cpi::create_staged_proposal(
+            CpiContext::new(self.govern_program.to_account_info(), accounts)
+                .with_signer(seeds)
+                .with_remaining_accounts(self.with_gate(remaining_accounts)),
+            bump,
+            capacity,
+        )
+    }
+
+    /// Creates a committed proposal, signing as the [Governor::proposal_creation_gate].
+    pub fn create_committed_proposal(
+        &self,
+        remaining_accounts: &[AccountInfo<'info>],
+        bump: u8,
+        instructions_hash: [u8; 32],
+        invoked_program_ids: Vec<Pubkey>,
+    ) -> Result<()> {
+        let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+        let accounts = govern::cpi::accounts::CreateCommittedProposal {
+            governor: self.governor.to_account_info(),
+            proposal: self.proposal.to_account_info(),
+            proposer: self.escrow_owner.to_account_info(),
+            payer: self.payer.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        };
+        govern::cpi::create_committed_proposal(
+            CpiContext::new(self.govern_program.to_account_info(), accounts)
+                .with_signer(seeds)
+                .with_remaining_accounts(self.with_gate(remaining_accounts)),
+            bump,
+            instructions_hash,
+            invoked_program_ids,
+        )
+    }
+
+    /// Creates a multiple-choice proposal, signing as the [Governor::proposal_creation_gate].
+    pub fn create_multiple_choice_proposal(
+        &self,
+        remaining_accounts: &[AccountInfo<'info>],
+        bump: u8,
+        options: Vec<Vec<ProposalInstruction>>,
+    ) -> Result<()> {
+        let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+        let accounts = govern::cpi::accounts::CreateMultipleChoiceProposal {
+            governor: self.governor.to_account_info(),
+            proposal: self.proposal.to_account_info(),
+            proposer: self.escrow_owner.to_account_info(),
+            payer: self.payer.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        };
+        govern::cpi::create_multiple_choice_proposal(
+            CpiContext::new(self.govern_program.to_account_info(), accounts)
+                .with_signer(seeds)
+                .with_remaining_accounts(self.with_gate(remaining_accounts)),
+            bump,
+            options,
+        )
+    }
+
+    /// Prepends the [Locker] to the `remaining_accounts` as the signing
+    /// [Governor::proposal_creation_gate].
+    fn with_gate(&self, remaining_accounts: &[AccountInfo<'info>]) -> Vec<AccountInfo<'info>> {
+        let mut gate = self.locker.to_account_info();
+        gate.is_signer = true;
+        let mut cpi_remaining_accounts = vec![gate];
+        cpi_remaining_accounts.extend_from_slice(remaining_accounts);
+        cpi_remaining_accounts
+    }
+
+    /// Conversion.
+    fn to_create_proposal_accounts(&self) -> govern::cpi::accounts::CreateProposal<'info> {
+        govern::cpi::accounts::CreateProposal {
+            governor: self.governor.to_account_info(),
+            proposal: self.proposal.to_account_info(),
+            proposer: self.escrow_owner.to_account_info(),
+            payer: self.payer.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        }
+    }
+}
+
+impl<'info> Validate<'info> for CreateProposal<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.locker, self.governor.proposal_creation_gate);
+        assert_keys_eq!(self.governor, self.locker.governor);
+        assert_keys_eq!(self.escrow.locker, self.locker);
+        assert_keys_eq!(self.escrow.owner, self.escrow_owner);
+
+        invariant!(
+            unwrap_int!(self
+                .locker
+                .params
+                .meets_proposal_min_votes(&self.escrow, Clock::get()?.unix_timestamp)),
+            "insufficient voting power to create a proposal"
+        );
+
+        Ok(())
+    }
+}
@@ -30,8 +30,23 @@ pub struct Exit<'info> {
 }
 
 impl<'info> Exit<'info> {
-    pub fn exit(&mut self) -> Result<()> {
+    /// Exits the [Escrow]. If [Locker::history_enabled] is set, the [LockerHistory]
+    /// must be the last of the `remaining_accounts`.
+    pub fn exit(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
         let seeds: &[&[&[u8]]] = escrow_seeds!(self.escrow);
+        let now = Clock::get()?.unix_timestamp;
+
+        let (locker_history, _) = split_locker_history(&self.locker, remaining_accounts)?;
+        if let Some(mut locker_history) = locker_history {
+            locker_history.record_escrow_change(
+                now,
+                self.escrow.amount,
+                self.escrow.escrow_ends_at,
+                0,
+                0,
+            )?;
+            locker_history.exit(&crate::ID)?;
+        }
 
         // transfer tokens from the escrow
         // if there are zero tokens in the escrow, short-circuit.
@@ -58,7 +73,7 @@ impl<'info> Exit<'info> {
             escrow_owner: self.escrow.owner,
             locker: locker.key(),
             locker_supply: locker.locked_supply,
-            timestamp: Clock::get()?.unix_timestamp,
+            timestamp: now,
             released_amount: self.escrow.amount,
         });
 
@@ -0,0 +1,158 @@
+use crate::*;
+use anchor_spl::token;
+
+/// Accounts for [locked_voter::exit_early].
+#[derive(Accounts)]
+pub struct ExitEarly<'info> {
+    /// The [Locker] being exited from.
+    #[account(mut)]
+    pub locker: Account<'info, Locker>,
+
+    /// The [Escrow] that is being closed.
+    #[account(mut, has_one = locker, close = payer)]
+    pub escrow: Account<'info, Escrow>,
+
+    /// Authority of the [Escrow].
+    pub escrow_owner: Signer<'info>,
+    /// Tokens locked up in the [Escrow].
+    #[account(mut, constraint = escrow.tokens == escrow_tokens.key())]
+    pub escrow_tokens: Account<'info, TokenAccount>,
+    /// Destination for the tokens to unlock, less the penalty.
+    #[account(mut)]
+    pub destination_tokens: Account<'info, TokenAccount>,
+
+    /// The [Governor].
+    pub governor: Account<'info, Governor>,
+    /// Token account of the smart wallet of the [Governor] which receives the penalty.
+    #[account(mut)]
+    pub treasury_tokens: Account<'info, TokenAccount>,
+
+    /// The payer to receive the rent refund.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ExitEarly<'info> {
+    /// Exits the [Escrow] before it ends, sending the penalty to the treasury.
+    ///
+    /// The [EscrowHistory] address of the [Escrow] must be provided, and it is
+    /// checkpointed if it exists; see [record_escrow_history].
+    /// If [Locker::history_enabled] is set, the [LockerHistory] must be the last remaining account.
+    pub fn exit_early(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let amount = self.escrow.amount;
+        let penalty = unwrap_int!(self
+            .locker
+            .params
+            .calculate_early_exit_penalty(&self.escrow, now));
+        let released_amount = unwrap_int!(amount.checked_sub(penalty));
+
+        let (locker_history, remaining_accounts) =
+            split_locker_history(&self.locker, remaining_accounts)?;
+        if let Some(mut locker_history) = locker_history {
+            locker_history.record_escrow_change(now, amount, self.escrow.escrow_ends_at, 0, 0)?;
+            locker_history.exit(&crate::ID)?;
+        }
+        // otherwise, the previous checkpoints would continue to grant voting power
+        record_escrow_history(
+            &self.locker,
+            self.escrow.key(),
+            &Escrow::default(),
+            remaining_accounts,
+            now,
+        )?;
+
+        let seeds: &[&[&[u8]]] = escrow_seeds!(self.escrow);
+        if penalty > 0 {
+            token::transfer(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    token::Transfer {
+                        from: self.escrow_tokens.to_account_info(),
+                        to: self.treasury_tokens.to_account_info(),
+                        authority: self.escrow.to_account_info(),
+                    },
+                )
+                .with_signer(seeds),
+                penalty,
+            )?;
+        }
+        if released_amount > 0 {
+            token::transfer(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    token::Transfer {
+                        from: self.escrow_tokens.to_account_info(),
+                        to: self.destination_tokens.to_account_info(),
+                        authority: self.escrow.to_account_info(),
+                    },
+                )
+                .with_signer(seeds),
+                released_amount,
+            )?;
+        }
+
+        // update the locker
+        let locker = &mut self.locker;
+        locker.locked_supply = unwrap_int!(locker.locked_supply.checked_sub(amount));
+
+        emit!(ExitEarlyEvent {
+            escrow_owner: self.escrow.owner,
+            locker: locker.key(),
+            timestamp: now,
+            locker_supply: locker.locked_supply,
+            released_amount,
+            penalty_amount: penalty,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for ExitEarly<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.locker, self.escrow.locker);
+        assert_keys_eq!(self.escrow.owner, self.escrow_owner);
+        assert_keys_eq!(self.escrow.tokens, self.escrow_tokens);
+        invariant!(
+            self.locker.params.early_exit_penalty_bps > 0,
+            EarlyExitDisabled
+        );
+        // otherwise, votes weighted at the end of voting could be cast again after relocking
+        invariant!(
+            self.locker.params.snapshot_voting_enabled,
+            EarlyExitRequiresSnapshotVoting
+        );
+
+        assert_keys_eq!(self.governor, self.locker.governor);
+        assert_keys_eq!(self.treasury_tokens.owner, self.governor.smart_wallet);
+        assert_keys_eq!(self.treasury_tokens.mint, self.locker.token_mint);
+
+        assert_keys_neq!(self.escrow_tokens, self.destination_tokens);
+        assert_keys_neq!(self.escrow_tokens, self.treasury_tokens);
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [locked_voter::exit_early].
+pub struct ExitEarlyEvent {
+    /// The owner of the [Escrow].
+    #[index]
+    pub escrow_owner: Pubkey,
+    /// The locker for the [Escrow].
+    #[index]
+    pub locker: Pubkey,
+    /// Timestamp for the event.
+    pub timestamp: i64,
+    /// The amount of tokens locked inside the [Locker].
+    pub locker_supply: u64,
+    /// The amount released from the [Escrow] to the owner.
+    pub released_amount: u64,
+    /// The amount of tokens sent to the treasury as a penalty.
+    pub penalty_amount: u64,
+}
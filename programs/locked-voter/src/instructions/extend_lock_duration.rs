@@ -0,0 +1,173 @@
+use crate::*;
+
+/// Accounts for [locked_voter::extend_lock_duration].
+#[derive(Accounts)]
+pub struct ExtendLockDuration<'info> {
+    /// [Locker].
+    #[account(mut)]
+    pub locker: Account<'info, Locker>,
+
+    /// [Escrow].
+    #[account(mut, has_one = locker)]
+    pub escrow: Account<'info, Escrow>,
+
+    /// Authority of the [Escrow].
+    pub escrow_owner: Signer<'info>,
+}
+
+/// Accounts for [locked_voter::extend_lock_duration_as_extender].
+#[derive(Accounts)]
+pub struct ExtendLockDurationAsExtender<'info> {
+    /// [Locker].
+    #[account(mut)]
+    pub locker: Account<'info, Locker>,
+
+    /// [Escrow].
+    #[account(mut, has_one = locker)]
+    pub escrow: Account<'info, Escrow>,
+
+    /// The [EscrowExtender] authorizing the [Self::extender].
+    pub escrow_extender: Account<'info, EscrowExtender>,
+
+    /// The extender approved by the [Escrow::owner].
+    pub extender: Signer<'info>,
+}
+
+impl<'info> ExtendLockDuration<'info> {
+    /// Relocks the [Escrow] for `duration` seconds from now without depositing tokens.
+    /// If [Locker::history_enabled] is set, the [LockerHistory] must be the last of the `remaining_accounts`.
+    /// If [LockerParams::snapshot_voting_enabled] is set, the [EscrowHistory] address must
+    /// precede it; see [record_escrow_history].
+    pub fn extend_lock_duration(
+        &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
+        duration: i64,
+    ) -> Result<()> {
+        extend_lock(
+            &mut self.locker,
+            &mut self.escrow,
+            remaining_accounts,
+            duration,
+            None,
+        )
+    }
+}
+
+impl<'info> ExtendLockDurationAsExtender<'info> {
+    /// Relocks the [Escrow] on behalf of its owner, as in [ExtendLockDuration::extend_lock_duration].
+    /// The [Escrow] may not end after the [EscrowExtender::max_escrow_ends_at].
+    pub fn extend_lock_duration(
+        &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
+        duration: i64,
+    ) -> Result<()> {
+        extend_lock(
+            &mut self.locker,
+            &mut self.escrow,
+            remaining_accounts,
+            duration,
+            Some(self.escrow_extender.max_escrow_ends_at),
+        )
+    }
+}
+
+/// Relocks the [Escrow] for `duration` seconds from now, recording the change
+/// in the [LockerHistory] and [EscrowHistory] as needed.
+/// If `max_escrow_ends_at` is provided, the [Escrow] may not end after it.
+fn extend_lock<'info>(
+    locker: &mut Account<'info, Locker>,
+    escrow: &mut Account<'info, Escrow>,
+    remaining_accounts: &[AccountInfo<'info>],
+    duration: i64,
+    max_escrow_ends_at: Option<i64>,
+) -> Result<()> {
+    let (locker_history, remaining_accounts) = split_locker_history(locker, remaining_accounts)?;
+
+    let prev_escrow_ends_at = escrow.escrow_ends_at;
+    let next_escrow_started_at = Clock::get()?.unix_timestamp;
+    let next_escrow_ends_at = locker.params.calculate_relocked_escrow_ends_at(
+        prev_escrow_ends_at,
+        next_escrow_started_at,
+        duration,
+    )?;
+    if let Some(max_escrow_ends_at) = max_escrow_ends_at {
+        invariant!(
+            next_escrow_ends_at <= max_escrow_ends_at,
+            EscrowExtenderLimitExceeded
+        );
+    }
+
+    escrow.record_lock_event(locker, 0, next_escrow_started_at, next_escrow_ends_at)?;
+
+    if let Some(mut locker_history) = locker_history {
+        locker_history.record_escrow_change(
+            next_escrow_started_at,
+            escrow.amount,
+            prev_escrow_ends_at,
+            escrow.amount,
+            escrow.escrow_ends_at,
+        )?;
+        locker_history.exit(&crate::ID)?;
+    }
+
+    emit!(ExtendLockEvent {
+        locker: locker.key(),
+        escrow_owner: escrow.owner,
+        amount: escrow.amount,
+        duration,
+        prev_escrow_ends_at,
+        next_escrow_ends_at,
+        next_escrow_started_at,
+    });
+
+    record_escrow_history(
+        locker,
+        escrow.key(),
+        escrow,
+        remaining_accounts,
+        next_escrow_started_at,
+    )?;
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for ExtendLockDuration<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.locker, self.escrow.locker);
+        assert_keys_eq!(self.escrow.owner, self.escrow_owner);
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for ExtendLockDurationAsExtender<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.locker, self.escrow.locker);
+        assert_keys_eq!(self.escrow_extender.escrow, self.escrow);
+        assert_keys_eq!(self.escrow_extender.extender, self.extender);
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [locked_voter::extend_lock_duration] and
+/// [locked_voter::extend_lock_duration_as_extender].
+pub struct ExtendLockEvent {
+    /// The locker of the [Escrow]
+    #[index]
+    pub locker: Pubkey,
+    /// The owner of the [Escrow].
+    #[index]
+    pub escrow_owner: Pubkey,
+    /// Amount of tokens locked inside the [Escrow].
+    pub amount: u64,
+    /// Duration of lock time.
+    pub duration: i64,
+    /// The previous timestamp that the [Escrow] ended at.
+    pub prev_escrow_ends_at: i64,
+    /// The new [Escrow] end time.
+    pub next_escrow_ends_at: i64,
+    /// The new [Escrow] start time.
+    pub next_escrow_started_at: i64,
+}
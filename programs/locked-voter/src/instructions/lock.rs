@@ -36,7 +36,18 @@ pub struct Lock<'info> {
 }
 
 impl<'info> Lock<'info> {
-    pub fn lock(&mut self, amount: u64, duration: i64) -> Result<()> {
+    /// Locks tokens. If [Locker::history_enabled] is set, the [LockerHistory]
+    /// must be the last of the `remaining_accounts`. If [LockerParams::snapshot_voting_enabled]
+    /// is set, the [EscrowHistory] address must precede it; see [record_escrow_history].
+    pub fn lock(
+        &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
+        amount: u64,
+        duration: i64,
+    ) -> Result<()> {
+        let (locker_history, remaining_accounts) =
+            split_locker_history(&self.locker, remaining_accounts)?;
+
         invariant!(
             unwrap_int!(duration.to_u64()) >= self.locker.params.min_stake_duration,
             LockupDurationTooShort
@@ -84,8 +95,20 @@ impl<'info> Lock<'info> {
 
         let locker = &mut self.locker;
         let escrow = &mut self.escrow;
+        let prev_amount = escrow.amount;
         escrow.record_lock_event(locker, amount, next_escrow_started_at, next_escrow_ends_at)?;
 
+        if let Some(mut locker_history) = locker_history {
+            locker_history.record_escrow_change(
+                next_escrow_started_at,
+                prev_amount,
+                prev_escrow_ends_at,
+                escrow.amount,
+                escrow.escrow_ends_at,
+            )?;
+            locker_history.exit(&crate::ID)?;
+        }
+
         emit!(LockEvent {
             locker: locker.key(),
             locker_supply: locker.locked_supply,
@@ -98,11 +121,19 @@ impl<'info> Lock<'info> {
             next_escrow_started_at,
         });
 
+        record_escrow_history(
+            &self.locker,
+            self.escrow.key(),
+            &self.escrow,
+            remaining_accounts,
+            next_escrow_started_at,
+        )?;
+
         Ok(())
     }
 
     pub fn check_whitelisted(&self, ra: &[AccountInfo]) -> Result<()> {
-        invariant!(ra.len() == 2, MustProvideWhitelist);
+        invariant!(ra.len() >= 2, MustProvideWhitelist);
         let accounts_iter = &mut ra.iter();
         let ix_sysvar_account_info = next_account_info(accounts_iter)?;
         assert_keys_eq!(ix_sysvar_account_info.key(), sysvar::instructions::ID);
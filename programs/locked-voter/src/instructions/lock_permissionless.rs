@@ -9,5 +9,5 @@ pub fn handler<'info>(
         !ctx.accounts.locker.params.whitelist_enabled,
         MustProvideWhitelist
     );
-    ctx.accounts.lock(amount, duration)
+    ctx.accounts.lock(ctx.remaining_accounts, amount, duration)
 }
@@ -19,7 +19,9 @@ pub fn handler<'info>(
     amount: u64,
     duration: i64,
 ) -> Result<()> {
-    ctx.accounts.lock.lock(amount, duration)
+    ctx.accounts
+        .lock
+        .lock(ctx.remaining_accounts, amount, duration)
 }
 
 impl<'info> Validate<'info> for LockWithWhitelistEntry<'info> {
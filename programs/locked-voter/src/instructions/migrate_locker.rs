@@ -0,0 +1,55 @@
+//! Instruction handler for [locked_voter::migrate_locker].
+
+use crate::*;
+use anchor_lang::Discriminator;
+
+/// Accounts for [locked_voter::migrate_locker].
+#[derive(Accounts)]
+pub struct MigrateLocker<'info> {
+    /// CHECK: The [Locker] to migrate. Validated in [MigrateLocker::validate],
+    /// since it cannot be deserialized before the migration.
+    #[account(mut, owner = crate::ID)]
+    pub locker: UncheckedAccount<'info>,
+    /// Payer of the additional rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateLocker<'info> {
+    pub fn migrate_locker(&mut self) -> Result<()> {
+        govern::realloc::grow_account(
+            &self.locker.to_account_info(),
+            &self.payer.to_account_info(),
+            &self.system_program.to_account_info(),
+            8 + Locker::LEN,
+        )?;
+
+        emit!(LockerMigrateEvent {
+            locker: self.locker.key(),
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for MigrateLocker<'info> {
+    fn validate(&self) -> Result<()> {
+        let data = self.locker.try_borrow_data()?;
+        invariant!(
+            data.len() >= 8 && data[..8] == Locker::discriminator(),
+            "account is not a locker"
+        );
+        invariant!(data.len() < 8 + Locker::LEN, LockerAlreadyMigrated);
+        Ok(())
+    }
+}
+
+/// Event called in [locked_voter::migrate_locker].
+#[event]
+pub struct LockerMigrateEvent {
+    /// The [Locker].
+    #[index]
+    pub locker: Pubkey,
+}
@@ -1,29 +1,53 @@
 //! Instruction processors.
 
 pub mod activate_proposal;
+pub mod approve_escrow_extender;
 pub mod approve_program_lock_privilege;
 pub mod cast_vote;
+pub mod checkpoint_escrow;
+pub mod checkpoint_locker;
+pub mod create_proposal;
 pub mod exit;
+pub mod exit_early;
+pub mod extend_lock_duration;
 pub mod lock;
 pub mod lock_permissionless;
 pub mod lock_with_whitelist;
 pub mod lock_with_whitelist_entry;
+pub mod migrate_locker;
 pub mod new_escrow;
+pub mod new_escrow_history;
 pub mod new_locker;
+pub mod new_locker_history;
+pub mod revoke_escrow_extender;
 pub mod revoke_program_lock_privilege;
 pub mod set_locker_params;
 pub mod set_vote_delegate;
+pub mod set_vote_rationale;
+pub mod withdraw;
 
 pub use activate_proposal::*;
+pub use approve_escrow_extender::*;
 pub use approve_program_lock_privilege::*;
 pub use cast_vote::*;
+pub use checkpoint_escrow::*;
+pub use checkpoint_locker::*;
+pub use create_proposal::*;
 pub use exit::*;
+pub use exit_early::*;
+pub use extend_lock_duration::*;
 pub use lock::*;
 pub use lock_permissionless::*;
 pub use lock_with_whitelist::*;
 pub use lock_with_whitelist_entry::*;
+pub use migrate_locker::*;
 pub use new_escrow::*;
+pub use new_escrow_history::*;
 pub use new_locker::*;
+pub use new_locker_history::*;
+pub use revoke_escrow_extender::*;
 pub use revoke_program_lock_privilege::*;
 pub use set_locker_params::*;
 pub use set_vote_delegate::*;
+pub use set_vote_rationale::*;
+pub use withdraw::*;
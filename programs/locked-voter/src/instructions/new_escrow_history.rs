@@ -0,0 +1,47 @@
+use crate::*;
+
+/// Accounts for [locked_voter::new_escrow_history].
+#[derive(Accounts)]
+pub struct NewEscrowHistory<'info> {
+    /// [Escrow].
+    pub escrow: Account<'info, Escrow>,
+
+    /// [EscrowHistory].
+    #[account(
+        init,
+        seeds = [
+            b"EscrowHistory".as_ref(),
+            escrow.key().to_bytes().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + EscrowHistory::LEN
+    )]
+    pub escrow_history: Account<'info, EscrowHistory>,
+
+    /// Payer of the initialization.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> NewEscrowHistory<'info> {
+    /// Creates a new [EscrowHistory], checkpointing the current lock of the [Escrow].
+    pub fn new_escrow_history(&mut self, bump: u8) -> Result<()> {
+        let escrow_history = &mut self.escrow_history;
+        escrow_history.escrow = self.escrow.key();
+        escrow_history.bump = bump;
+        escrow_history.checkpoints = vec![];
+        escrow_history.record(&self.escrow, Clock::get()?.unix_timestamp, 0)?;
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for NewEscrowHistory<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -36,6 +36,8 @@ pub struct NewLocker<'info> {
 impl<'info> NewLocker<'info> {
     /// Creates a new [Locker].
     pub fn new_locker(&mut self, bump: u8, params: LockerParams) -> Result<()> {
+        params.validate()?;
+
         let locker = &mut self.locker;
         locker.token_mint = self.token_mint.key();
         locker.governor = self.governor.key();
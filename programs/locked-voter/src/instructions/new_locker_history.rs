@@ -0,0 +1,76 @@
+//! Instruction handler for [locked_voter::new_locker_history].
+
+use crate::*;
+
+/// Accounts for [locked_voter::new_locker_history].
+#[derive(Accounts)]
+pub struct NewLockerHistory<'info> {
+    /// [Locker].
+    #[account(mut)]
+    pub locker: Account<'info, Locker>,
+
+    /// [LockerHistory].
+    #[account(
+        init,
+        seeds = [
+            b"LockerHistory".as_ref(),
+            locker.key().to_bytes().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + LockerHistory::LEN
+    )]
+    pub locker_history: Account<'info, LockerHistory>,
+
+    /// The [Governor].
+    pub governor: Account<'info, Governor>,
+    /// The smart wallet on the [Governor].
+    pub smart_wallet: Signer<'info>,
+
+    /// Payer of the initialization.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> NewLockerHistory<'info> {
+    /// Creates a new [LockerHistory] and enables it on the [Locker].
+    pub fn new_locker_history(&mut self, bump: u8) -> Result<()> {
+        let timestamp = Clock::get()?.unix_timestamp;
+        self.locker_history.init(self.locker.key(), bump, timestamp);
+        self.locker.history_enabled = true;
+
+        emit!(NewLockerHistoryEvent {
+            locker: self.locker.key(),
+            locker_history: self.locker_history.key(),
+            timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for NewLockerHistory<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.governor, self.locker.governor, "governor mismatch");
+        assert_keys_eq!(self.smart_wallet, self.governor.smart_wallet);
+        // the voting power of existing escrows cannot be backfilled
+        invariant!(self.locker.locked_supply == 0, LockerNotEmpty);
+        self.locker.params.validate_history()?;
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [locked_voter::new_locker_history].
+pub struct NewLockerHistoryEvent {
+    /// The [Locker].
+    #[index]
+    pub locker: Pubkey,
+    /// The [LockerHistory] being created.
+    pub locker_history: Pubkey,
+    /// Timestamp for the event.
+    pub timestamp: i64,
+}
@@ -0,0 +1,53 @@
+//! Handler for [locked_voter::revoke_escrow_extender].
+
+use crate::*;
+
+/// Accounts for [locked_voter::revoke_escrow_extender].
+#[derive(Accounts)]
+pub struct RevokeEscrowExtender<'info> {
+    /// The [Escrow].
+    pub escrow: Account<'info, Escrow>,
+    /// [EscrowExtender].
+    #[account(mut, has_one = escrow, close = payer)]
+    pub escrow_extender: Account<'info, EscrowExtender>,
+
+    /// Owner of the [Escrow].
+    pub escrow_owner: Signer<'info>,
+
+    /// Receiver of the rent refund.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+impl<'info> RevokeEscrowExtender<'info> {
+    /// Emit event that [EscrowExtender] was closed.
+    pub fn revoke_escrow_extender(&mut self) -> Result<()> {
+        emit!(RevokeEscrowExtenderEvent {
+            escrow: self.escrow_extender.escrow,
+            extender: self.escrow_extender.extender,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for RevokeEscrowExtender<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.escrow.owner, self.escrow_owner);
+        assert_keys_eq!(self.escrow_extender.escrow, self.escrow);
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [locked_voter::revoke_escrow_extender].
+pub struct RevokeEscrowExtenderEvent {
+    /// The [Escrow].
+    #[index]
+    pub escrow: Pubkey,
+    /// The account no longer authorized to extend the lock of the [Escrow].
+    pub extender: Pubkey,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
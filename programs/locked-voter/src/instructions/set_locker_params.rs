@@ -16,6 +16,15 @@ pub struct SetLockerParams<'info> {
 
 impl<'info> SetLockerParams<'info> {
     pub fn set_locker_params(&mut self, params: LockerParams) -> Result<()> {
+        params.validate()?;
+        if self.locker.history_enabled {
+            params.validate_history()?;
+        }
+        // existing escrows have no history to snapshot their voting power from
+        if params.snapshot_voting_enabled && !self.locker.params.snapshot_voting_enabled {
+            invariant!(self.locker.locked_supply == 0, LockerNotEmpty);
+        }
+
         let prev_params = self.locker.params;
         self.locker.params = params;
 
@@ -0,0 +1,57 @@
+use crate::*;
+use govern::VoteRationale;
+
+/// Accounts for [locked_voter::set_vote_rationale].
+#[derive(Accounts)]
+pub struct SetVoteRationale<'info> {
+    /// The [Locker].
+    pub locker: Account<'info, Locker>,
+    /// The [Escrow] that voted.
+    pub escrow: Account<'info, Escrow>,
+    /// Vote delegate of the [Escrow].
+    pub vote_delegate: Signer<'info>,
+
+    /// The [Proposal] voted on.
+    pub proposal: Account<'info, Proposal>,
+    /// The [Vote].
+    pub vote: Account<'info, Vote>,
+    /// The [VoteRationale].
+    #[account(mut)]
+    pub vote_rationale: Account<'info, VoteRationale>,
+
+    /// The [Governor].
+    pub governor: Account<'info, Governor>,
+    /// The [govern] program.
+    pub govern_program: Program<'info, govern::program::Govern>,
+}
+
+impl<'info> SetVoteRationale<'info> {
+    pub fn set_vote_rationale(&mut self, content_hash: [u8; 32], uri: String) -> Result<()> {
+        let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+        let cpi_ctx = CpiContext::new(
+            self.govern_program.to_account_info(),
+            govern::cpi::accounts::SetVoteRationale {
+                governor: self.governor.to_account_info(),
+                proposal: self.proposal.to_account_info(),
+                vote: self.vote.to_account_info(),
+                vote_rationale: self.vote_rationale.to_account_info(),
+                authority: self.locker.to_account_info(),
+            },
+        )
+        .with_signer(seeds);
+
+        govern::cpi::set_vote_rationale(cpi_ctx, content_hash, uri)
+    }
+}
+
+impl<'info> Validate<'info> for SetVoteRationale<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.escrow.locker, self.locker);
+        assert_keys_eq!(self.escrow.vote_delegate, self.vote_delegate);
+        assert_keys_eq!(self.locker.governor, self.governor);
+        assert_keys_eq!(self.proposal.governor, self.governor);
+        assert_keys_eq!(self.vote.proposal, self.proposal);
+        assert_keys_eq!(self.vote.voter, self.escrow.owner);
+        Ok(())
+    }
+}
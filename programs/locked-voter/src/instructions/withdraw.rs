@@ -0,0 +1,126 @@
+use crate::*;
+use anchor_spl::token;
+
+/// Accounts for [locked_voter::withdraw].
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    /// The [Locker] being withdrawn from.
+    #[account(mut)]
+    pub locker: Account<'info, Locker>,
+
+    /// The [Escrow] that is being withdrawn from.
+    #[account(mut, has_one = locker)]
+    pub escrow: Account<'info, Escrow>,
+
+    /// Authority of the [Escrow].
+    pub escrow_owner: Signer<'info>,
+    /// Tokens locked up in the [Escrow].
+    #[account(mut, constraint = escrow.tokens == escrow_tokens.key())]
+    pub escrow_tokens: Account<'info, TokenAccount>,
+    /// Destination for the tokens to unlock.
+    #[account(mut)]
+    pub destination_tokens: Account<'info, TokenAccount>,
+
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> Withdraw<'info> {
+    /// Withdraws `amount` tokens from the [Escrow]. If [Locker::history_enabled] is set,
+    /// the [LockerHistory] must be the last of the `remaining_accounts`.
+    pub fn withdraw(
+        &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
+        amount: u64,
+    ) -> Result<()> {
+        invariant!(amount <= self.escrow.amount, InsufficientEscrowBalance);
+        let now = Clock::get()?.unix_timestamp;
+
+        let prev_amount = self.escrow.amount;
+        let next_amount = unwrap_int!(prev_amount.checked_sub(amount));
+
+        let (locker_history, _) = split_locker_history(&self.locker, remaining_accounts)?;
+        if let Some(mut locker_history) = locker_history {
+            locker_history.record_escrow_change(
+                now,
+                prev_amount,
+                self.escrow.escrow_ends_at,
+                next_amount,
+                self.escrow.escrow_ends_at,
+            )?;
+            locker_history.exit(&crate::ID)?;
+        }
+
+        // transfer tokens from the escrow
+        // if withdrawing zero tokens, short-circuit.
+        if amount > 0 {
+            let seeds: &[&[&[u8]]] = escrow_seeds!(self.escrow);
+            token::transfer(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    token::Transfer {
+                        from: self.escrow_tokens.to_account_info(),
+                        to: self.destination_tokens.to_account_info(),
+                        authority: self.escrow.to_account_info(),
+                    },
+                )
+                .with_signer(seeds),
+                amount,
+            )?;
+        }
+
+        // update the escrow and locker
+        self.escrow.amount = next_amount;
+        let locker = &mut self.locker;
+        locker.locked_supply = unwrap_int!(locker.locked_supply.checked_sub(amount));
+
+        emit!(WithdrawEscrowEvent {
+            escrow_owner: self.escrow.owner,
+            locker: locker.key(),
+            timestamp: now,
+            locker_supply: locker.locked_supply,
+            withdrawn_amount: amount,
+            remaining_amount: next_amount,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for Withdraw<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.locker, self.escrow.locker);
+        assert_keys_eq!(self.escrow.owner, self.escrow_owner);
+        assert_keys_eq!(self.escrow.tokens, self.escrow_tokens);
+        let now = Clock::get()?.unix_timestamp;
+        msg!(
+            "now: {}; escrow_ends_at: {}",
+            now,
+            self.escrow.escrow_ends_at
+        );
+        invariant!(self.escrow.escrow_ends_at < now, EscrowNotEnded);
+
+        assert_keys_neq!(self.escrow_tokens, self.destination_tokens);
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [locked_voter::withdraw].
+pub struct WithdrawEscrowEvent {
+    /// The owner of the [Escrow].
+    #[index]
+    pub escrow_owner: Pubkey,
+    /// The locker for the [Escrow].
+    #[index]
+    pub locker: Pubkey,
+    /// Timestamp for the event.
+    pub timestamp: i64,
+    /// The amount of tokens locked inside the [Locker].
+    pub locker_supply: u64,
+    /// The amount withdrawn from the [Escrow].
+    pub withdrawn_amount: u64,
+    /// The amount of tokens remaining in the [Escrow].
+    pub remaining_amount: u64,
+}
@@ -7,13 +7,15 @@ pub mod macros;
 
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
-use govern::{Governor, Proposal, Vote};
+use govern::{Governor, Proposal, Vote, BPS_DENOMINATOR};
 use vipers::prelude::*;
 
+pub mod history;
 mod instructions;
 pub mod locker;
 mod state;
 
+pub use history::*;
 pub use instructions::*;
 pub use state::*;
 
@@ -41,6 +43,22 @@ pub mod locked_voter {
         ctx.accounts.new_escrow(unwrap_bump!(ctx, "escrow"))
     }
 
+    /// Creates a new [EscrowHistory] for an [Escrow], which is required to vote
+    /// if [LockerParams::snapshot_voting_enabled] is set. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn new_escrow_history(ctx: Context<NewEscrowHistory>, _bump: u8) -> Result<()> {
+        ctx.accounts
+            .new_escrow_history(unwrap_bump!(ctx, "escrow_history"))
+    }
+
+    /// Records the current lock of an [Escrow] in its [EscrowHistory], if it has changed
+    /// since the latest checkpoint. Locks are recorded automatically once the [EscrowHistory]
+    /// exists, so this is only needed to bring a stale history up to date. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn checkpoint_escrow(ctx: Context<CheckpointEscrow>) -> Result<()> {
+        ctx.accounts.checkpoint_escrow()
+    }
+
     /// Stakes `amount` tokens into the [Escrow].
     /// WARNING: if the program has a whitelist, one should use [crate::locked_voter::lock_with_whitelist] instead.
     /// This version of the instruction is deprecated.
@@ -54,7 +72,7 @@ pub mod locked_voter {
         if ctx.accounts.locker.params.whitelist_enabled {
             ctx.accounts.check_whitelisted(ctx.remaining_accounts)?;
         }
-        ctx.accounts.lock(amount, duration)
+        ctx.accounts.lock(ctx.remaining_accounts, amount, duration)
     }
 
     /// Stakes `amount` tokens into the [Escrow], if there is a whitelist.
@@ -87,22 +105,169 @@ pub mod locked_voter {
         instructions::lock_permissionless::handler(ctx, amount, duration)
     }
 
+    /// Extends the lockup of the [Escrow] to `duration` seconds from now without depositing tokens.
+    #[access_control(ctx.accounts.validate())]
+    pub fn extend_lock_duration<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExtendLockDuration<'info>>,
+        duration: i64,
+    ) -> Result<()> {
+        ctx.accounts
+            .extend_lock_duration(ctx.remaining_accounts, duration)
+    }
+
+    /// Extends the lockup of the [Escrow] on behalf of its owner, as in [extend_lock_duration].
+    /// This may only be called by an extender approved via [approve_escrow_extender],
+    /// and the lock may not be extended past its [EscrowExtender::max_escrow_ends_at].
+    #[access_control(ctx.accounts.validate())]
+    pub fn extend_lock_duration_as_extender<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExtendLockDurationAsExtender<'info>>,
+        duration: i64,
+    ) -> Result<()> {
+        ctx.accounts
+            .extend_lock_duration(ctx.remaining_accounts, duration)
+    }
+
+    /// Creates a new [EscrowExtender], authorizing an account to extend the lock of the [Escrow]
+    /// up to `max_escrow_ends_at`. This may only be called by the [Escrow::owner].
+    #[access_control(ctx.accounts.validate())]
+    pub fn approve_escrow_extender(
+        ctx: Context<ApproveEscrowExtender>,
+        _bump: u8,
+        max_escrow_ends_at: i64,
+    ) -> Result<()> {
+        ctx.accounts
+            .approve_escrow_extender(unwrap_bump!(ctx, "escrow_extender"), max_escrow_ends_at)
+    }
+
+    /// Closes an [EscrowExtender], revoking its authorization.
+    /// This may only be called by the [Escrow::owner].
+    #[access_control(ctx.accounts.validate())]
+    pub fn revoke_escrow_extender(ctx: Context<RevokeEscrowExtender>) -> Result<()> {
+        ctx.accounts.revoke_escrow_extender()
+    }
+
     /// Exits the DAO; i.e., withdraws all staked tokens in an [Escrow] if the [Escrow] is unlocked.
     #[access_control(ctx.accounts.validate())]
-    pub fn exit(ctx: Context<Exit>) -> Result<()> {
-        ctx.accounts.exit()
+    pub fn exit<'info>(ctx: Context<'_, '_, '_, 'info, Exit<'info>>) -> Result<()> {
+        ctx.accounts.exit(ctx.remaining_accounts)
+    }
+
+    /// Exits the DAO before the [Escrow] is unlocked, forfeiting a penalty to the treasury
+    /// of the [Governor] according to [LockerParams::early_exit_penalty_bps].
+    /// This requires [LockerParams::snapshot_voting_enabled], so that the exited tokens
+    /// cannot be locked again to vote twice on the same proposal.
+    #[access_control(ctx.accounts.validate())]
+    pub fn exit_early<'info>(ctx: Context<'_, '_, '_, 'info, ExitEarly<'info>>) -> Result<()> {
+        ctx.accounts.exit_early(ctx.remaining_accounts)
+    }
+
+    /// Withdraws `amount` tokens from an [Escrow] if the [Escrow] is unlocked,
+    /// leaving the [Escrow] open so that it may be locked again.
+    #[access_control(ctx.accounts.validate())]
+    pub fn withdraw<'info>(
+        ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        ctx.accounts.withdraw(ctx.remaining_accounts, amount)
+    }
+
+    /// Creates a proposal through the [Locker], which signs as the
+    /// [Governor::proposal_creation_gate].
+    /// The [Escrow] must have at least [LockerParams::proposal_activation_min_votes].
+    /// Remaining accounts are passed through to [govern::govern::create_proposal].
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateProposal<'info>>,
+        bump: u8,
+        instructions: Vec<govern::ProposalInstruction>,
+    ) -> Result<()> {
+        ctx.accounts
+            .create_proposal(ctx.remaining_accounts, bump, instructions)
+    }
+
+    /// Creates an emergency proposal through the [Locker], as in [create_proposal].
+    /// Remaining accounts are passed through to [govern::govern::create_emergency_proposal].
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_emergency_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateProposal<'info>>,
+        bump: u8,
+        instructions: Vec<govern::ProposalInstruction>,
+    ) -> Result<()> {
+        ctx.accounts
+            .create_emergency_proposal(ctx.remaining_accounts, bump, instructions)
+    }
+
+    /// Creates a staged proposal through the [Locker], as in [create_proposal].
+    /// Remaining accounts are passed through to [govern::govern::create_staged_proposal].
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_staged_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateProposal<'info>>,
+        bump: u8,
+        capacity: u32,
+    ) -> Result<()> {
+        ctx.accounts
+            .create_staged_proposal(ctx.remaining_accounts, bump, capacity)
+    }
+
+    /// Creates a committed proposal through the [Locker], as in [create_proposal].
+    /// Remaining accounts are passed through to [govern::govern::create_committed_proposal].
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_committed_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateProposal<'info>>,
+        bump: u8,
+        instructions_hash: [u8; 32],
+        invoked_program_ids: Vec<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts.create_committed_proposal(
+            ctx.remaining_accounts,
+            bump,
+            instructions_hash,
+            invoked_program_ids,
+        )
+    }
+
+    /// Creates a multiple-choice proposal through the [Locker], as in [create_proposal].
+    /// Remaining accounts are passed through to [govern::govern::create_multiple_choice_proposal].
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_multiple_choice_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateProposal<'info>>,
+        bump: u8,
+        options: Vec<Vec<govern::ProposalInstruction>>,
+    ) -> Result<()> {
+        ctx.accounts
+            .create_multiple_choice_proposal(ctx.remaining_accounts, bump, options)
     }
 
     /// Activates a proposal.
+    /// Remaining accounts are passed through to [govern::activate_proposal].
     #[access_control(ctx.accounts.validate())]
-    pub fn activate_proposal(ctx: Context<ActivateProposal>) -> Result<()> {
-        ctx.accounts.activate_proposal()
+    pub fn activate_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ActivateProposal<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.activate_proposal(ctx.remaining_accounts)
     }
 
     /// Casts a vote.
+    ///
+    /// If [LockerParams::snapshot_voting_enabled] is set, the first remaining account
+    /// must be the [EscrowHistory] of the [Escrow].
+    #[access_control(ctx.accounts.validate())]
+    pub fn cast_vote<'info>(
+        ctx: Context<'_, '_, '_, 'info, CastVote<'info>>,
+        side: u8,
+    ) -> Result<()> {
+        ctx.accounts.cast_vote(ctx.remaining_accounts, side)
+    }
+
+    /// Sets the rationale of the [Escrow]'s vote.
+    /// This may be called by the vote delegate of the [Escrow].
     #[access_control(ctx.accounts.validate())]
-    pub fn cast_vote(ctx: Context<CastVote>, side: u8) -> Result<()> {
-        ctx.accounts.cast_vote(side)
+    pub fn set_vote_rationale(
+        ctx: Context<SetVoteRationale>,
+        content_hash: [u8; 32],
+        uri: String,
+    ) -> Result<()> {
+        ctx.accounts.set_vote_rationale(content_hash, uri)
     }
 
     /// Delegate escrow vote.
@@ -117,6 +282,34 @@ pub mod locked_voter {
         ctx.accounts.set_locker_params(params)
     }
 
+    /// Migrates a [Locker] created before [LockerParams::snapshot_voting_enabled] existed
+    /// to the current [Locker] layout. Anyone may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_locker(ctx: Context<MigrateLocker>) -> Result<()> {
+        ctx.accounts.migrate_locker()
+    }
+
+    /// Creates a new [LockerHistory], tracking the total voting power of the [Locker].
+    /// This may only be called by the smart wallet of the [Governor], before any tokens are locked,
+    /// since the voting power of existing [Escrow]s cannot be backfilled. Existing [Locker]s
+    /// with locked tokens cannot track their total voting power.
+    /// The [LockerParams::max_stake_duration] must be at most [MAX_HISTORY_STAKE_DURATION].
+    ///
+    /// Once enabled, the [LockerHistory] must be provided as the last remaining account
+    /// to all instructions which lock or exit, and the total voting power is reported to
+    /// the [Governor] when activating proposals.
+    #[access_control(ctx.accounts.validate())]
+    pub fn new_locker_history(ctx: Context<NewLockerHistory>, _bump: u8) -> Result<()> {
+        ctx.accounts
+            .new_locker_history(unwrap_bump!(ctx, "locker_history"))
+    }
+
+    /// Advances the [LockerHistory] to the current time. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn checkpoint_locker(ctx: Context<CheckpointLocker>) -> Result<()> {
+        ctx.accounts.checkpoint_locker()
+    }
+
     /// Creates a new [LockerWhitelistEntry] to whitelist program from CPI.
     #[access_control(ctx.accounts.validate())]
     pub fn approve_program_lock_privilege(
@@ -155,4 +348,28 @@ pub enum ErrorCode {
     MustCallLockWithWhitelistEntry,
     #[msg("Must call `lock_permissionless` since this DAO does not have a CPI whitelist.")]
     MustCallLockPermissionless,
+    #[msg("Snapshot voting is enabled; please provide the escrow history.")]
+    MustProvideEscrowHistory,
+    #[msg("Locker has already been migrated.")]
+    LockerAlreadyMigrated,
+    #[msg("Locker must not have any locked tokens.")]
+    LockerNotEmpty,
+    #[msg("Locker history is enabled; please provide the locker history.")]
+    MustProvideLockerHistory,
+    #[msg("Escrow ends too far in the future to be tracked by the locker history.")]
+    LockTooLongForHistory,
+    #[msg("Proposals which may be queued early require snapshot voting.")]
+    EarlyQueueRequiresSnapshotVoting,
+    #[msg("Max stake duration is too long to be tracked by the locker history.")]
+    StakeDurationTooLongForHistory,
+    #[msg("Escrow does not have enough tokens.")]
+    InsufficientEscrowBalance,
+    #[msg("Early exits are disabled for this locker.")]
+    EarlyExitDisabled,
+    #[msg("Early exit penalty must be at most 10,000 basis points.")]
+    InvalidEarlyExitPenalty,
+    #[msg("Early exits require snapshot voting.")]
+    EarlyExitRequiresSnapshotVoting,
+    #[msg("The lock would end after the latest time approved for the extender.")]
+    EscrowExtenderLimitExceeded,
 }
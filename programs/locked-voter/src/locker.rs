@@ -39,6 +39,76 @@ impl LockerParams {
 
         Some(power)
     }
+
+    /// Checks if an [Escrow] has at least [LockerParams::proposal_activation_min_votes]
+    /// at `now`, as required to create a proposal through the [Locker].
+    pub fn meets_proposal_min_votes(&self, escrow: &Escrow, now: i64) -> Option<bool> {
+        Some(self.calculate_voter_power(escrow, now)? >= self.proposal_activation_min_votes)
+    }
+
+    /// Calculates when an [Escrow] ending at `prev_escrow_ends_at` ends once it is relocked
+    /// at `now` for `duration` seconds, which must be within the stake duration bounds
+    /// and must not shorten the lock.
+    pub fn calculate_relocked_escrow_ends_at(
+        &self,
+        prev_escrow_ends_at: i64,
+        now: i64,
+        duration: i64,
+    ) -> Result<i64> {
+        let duration_u64 = unwrap_int!(duration.to_u64());
+        invariant!(
+            duration_u64 >= self.min_stake_duration,
+            LockupDurationTooShort
+        );
+        invariant!(
+            duration_u64 <= self.max_stake_duration,
+            LockupDurationTooLong
+        );
+
+        let next_escrow_ends_at = unwrap_int!(now.checked_add(duration));
+        invariant!(
+            next_escrow_ends_at >= prev_escrow_ends_at,
+            RefreshCannotShorten
+        );
+        Ok(next_escrow_ends_at)
+    }
+
+    /// Calculates the number of tokens forfeited by exiting an [Escrow] at `now`, before it ends.
+    /// See [LockerParams::early_exit_penalty_bps].
+    pub fn calculate_early_exit_penalty(&self, escrow: &Escrow, now: i64) -> Option<u64> {
+        if now >= escrow.escrow_ends_at {
+            return Some(0);
+        }
+        let seconds_until_lockup_expiry = escrow.escrow_ends_at.checked_sub(now)?;
+        // remaining seconds, clamped to the maximum duration
+        let relevant_seconds_until_lockup_expiry = seconds_until_lockup_expiry
+            .to_u64()?
+            .min(self.max_stake_duration);
+
+        // the locked tokens, scaled by the fraction of the max stake duration remaining
+        let time_weighted_amount = (escrow.amount as u128)
+            .checked_mul(relevant_seconds_until_lockup_expiry.into())?
+            .checked_div(self.max_stake_duration.into())?;
+
+        time_weighted_amount
+            .checked_mul(self.early_exit_penalty_bps.into())?
+            .checked_div(BPS_DENOMINATOR.into())?
+            .to_u64()
+    }
+
+    /// Calculates the total amount of voting power of all [Escrow]s tracked by a [LockerHistory]
+    /// at the time of the given [LockerPoint].
+    ///
+    /// This is the sum of [Self::calculate_voter_power] over all [Escrow]s,
+    /// except that each [Escrow] is considered to end at the next week boundary and
+    /// its remaining lockup is not clamped to the maximum duration.
+    pub fn calculate_total_voter_power(&self, point: &LockerPoint) -> Option<u64> {
+        point
+            .bias
+            .checked_mul(self.max_stake_vote_multiplier.into())?
+            .checked_div(self.max_stake_duration.into())?
+            .to_u64()
+    }
 }
 
 #[cfg(test)]
@@ -143,5 +213,117 @@ mod tests {
             let power = locker_params.calculate_voter_power(&escrow, current_ts).unwrap();
             assert_eq!(power, 0);
         }
+
+        #[test]
+        fn test_early_exit_penalty_bounded(
+            amount in 0..=u64::MAX,
+            early_exit_penalty_bps in 0..=BPS_DENOMINATOR as u16,
+            max_stake_duration_years in 1..=10u64,
+            (escrow_ends_at, now) in total_and_intermediate_ts(),
+        ) {
+            let locker_params = &LockerParams {
+                max_stake_duration: max_stake_duration_years * ONE_YEAR,
+                early_exit_penalty_bps,
+                ..LockerParams::default()
+            };
+            let escrow = Escrow {
+                escrow_started_at: now,
+                escrow_ends_at,
+                amount,
+                ..Escrow::default()
+            };
+            let penalty = locker_params.calculate_early_exit_penalty(&escrow, now).unwrap();
+            assert!(penalty <= amount);
+            let later_penalty = locker_params
+                .calculate_early_exit_penalty(&escrow, now + (escrow_ends_at - now) / 2)
+                .unwrap();
+            assert!(later_penalty <= penalty);
+            assert_eq!(
+                locker_params.calculate_early_exit_penalty(&escrow, escrow_ends_at).unwrap(),
+                0
+            );
+        }
+    }
+
+    #[test]
+    fn test_meets_proposal_min_votes() {
+        let locker_params = &LockerParams {
+            max_stake_duration: 4 * ONE_YEAR,
+            max_stake_vote_multiplier: 1,
+            proposal_activation_min_votes: 50_000,
+            ..LockerParams::default()
+        };
+        let escrow = Escrow {
+            escrow_started_at: 100,
+            escrow_ends_at: (100 + locker_params.max_stake_duration).to_i64().unwrap(),
+            amount: 100_000,
+            ..Escrow::default()
+        };
+        assert_eq!(
+            locker_params.meets_proposal_min_votes(&escrow, 100),
+            Some(true)
+        );
+        // exactly half of the lockup remaining
+        let halfway = 100 + (2 * ONE_YEAR).to_i64().unwrap();
+        assert_eq!(
+            locker_params.meets_proposal_min_votes(&escrow, halfway),
+            Some(true)
+        );
+        assert_eq!(
+            locker_params.meets_proposal_min_votes(&escrow, halfway + 1),
+            Some(false)
+        );
+        assert_eq!(
+            locker_params.meets_proposal_min_votes(&escrow, escrow.escrow_ends_at),
+            Some(false)
+        );
+        assert_eq!(locker_params.meets_proposal_min_votes(&escrow, 0), None);
+    }
+
+    fn assert_error(result: Result<i64>, code: crate::ErrorCode) {
+        match result.unwrap_err() {
+            Error::AnchorError(err) => assert_eq!(err.error_code_number, u32::from(code)),
+            err => panic!("unexpected error: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn test_relocked_escrow_ends_at() {
+        let locker_params = &LockerParams {
+            min_stake_duration: ONE_DAY,
+            max_stake_duration: ONE_YEAR,
+            ..LockerParams::default()
+        };
+        let day = ONE_DAY.to_i64().unwrap();
+        let year = ONE_YEAR.to_i64().unwrap();
+        let now = 1_000;
+
+        assert_eq!(
+            locker_params
+                .calculate_relocked_escrow_ends_at(0, now, day)
+                .unwrap(),
+            now + day
+        );
+        assert_eq!(
+            locker_params
+                .calculate_relocked_escrow_ends_at(now + year, now, year)
+                .unwrap(),
+            now + year
+        );
+        assert_error(
+            locker_params.calculate_relocked_escrow_ends_at(0, now, day - 1),
+            crate::ErrorCode::LockupDurationTooShort,
+        );
+        assert_error(
+            locker_params.calculate_relocked_escrow_ends_at(0, now, year + 1),
+            crate::ErrorCode::LockupDurationTooLong,
+        );
+        assert!(locker_params
+            .calculate_relocked_escrow_ends_at(0, now, -1)
+            .is_err());
+        assert_error(
+            locker_params.calculate_relocked_escrow_ends_at(now + day + 1, now, day),
+            crate::ErrorCode::RefreshCannotShorten,
+        );
     }
 }
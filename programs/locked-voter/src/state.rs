@@ -2,6 +2,7 @@
 #![deny(missing_docs)]
 
 use anchor_lang::solana_program::pubkey::PUBKEY_BYTES;
+use num_traits::ToPrimitive;
 
 use crate::*;
 
@@ -21,11 +22,29 @@ pub struct Locker {
     pub governor: Pubkey,
     /// Mutable parameters of how a [Locker] should behave.
     pub params: LockerParams,
+
+    /// Whether the [Locker] tracks the total voting power of its [Escrow]s in a [LockerHistory].
+    /// If so, instructions which change the lock of an [Escrow] must be provided the [LockerHistory].
+    pub history_enabled: bool,
+
+    /// The longest voting period, including extensions, of any proposal activated with
+    /// [LockerParams::snapshot_voting_enabled], in seconds.
+    /// [EscrowHistory] checkpoints are retained for at least this long, so that they
+    /// remain available while the proposals they may be needed for are being voted on.
+    pub snapshot_retention_seconds: u64,
 }
 
 impl Locker {
     /// Number of bytes in a [Locker].
-    pub const LEN: usize = PUBKEY_BYTES + 1 + PUBKEY_BYTES + 8 + PUBKEY_BYTES + LockerParams::LEN;
+    pub const LEN: usize =
+        PUBKEY_BYTES + 1 + PUBKEY_BYTES + 8 + PUBKEY_BYTES + LockerParams::LEN + 1 + 8;
+
+    /// Number of bytes in a [Locker] created before [LockerParams::snapshot_voting_enabled] existed.
+    ///
+    /// Fields added since then must treat a zero value as the legacy behavior,
+    /// since [crate::locked_voter::migrate_locker] zero-fills the new space.
+    pub const LEGACY_LEN: usize =
+        PUBKEY_BYTES + 1 + PUBKEY_BYTES + 8 + PUBKEY_BYTES + 1 + 1 + 8 * 3;
 }
 
 /// Contains parameters for the [Locker].
@@ -42,11 +61,44 @@ pub struct LockerParams {
     pub max_stake_duration: u64,
     /// Minimum number of votes required to activate a proposal.
     pub proposal_activation_min_votes: u64,
+    /// If true, votes are weighted by the voting power of each [Escrow] as of the
+    /// activation of the proposal, as recorded in its [EscrowHistory].
+    /// Otherwise, votes are weighted by the voting power each [Escrow] will have when voting ends.
+    ///
+    /// This may only be enabled while the [Locker] has no locked tokens, since [Escrow]s without
+    /// an [EscrowHistory] have no voting power as of any activation. Each [Escrow] must create its
+    /// [EscrowHistory] via [crate::locked_voter::new_escrow_history] before locking tokens;
+    /// locks made without one are not recorded.
+    pub snapshot_voting_enabled: bool,
+    /// The penalty for exiting an [Escrow] early via [crate::locked_voter::exit_early],
+    /// in basis points of the locked tokens, for an [Escrow] with the maximum stake duration remaining.
+    /// The penalty is proportional to the remaining lock time.
+    /// If zero, [Escrow]s may not be exited early. Early exits also require
+    /// [LockerParams::snapshot_voting_enabled].
+    pub early_exit_penalty_bps: u16,
 }
 
 impl LockerParams {
     /// Number of bytes in a [LockerParams].
-    pub const LEN: usize = 1 + 1 + 8 + 8 + 8;
+    pub const LEN: usize = 1 + 1 + 8 + 8 + 8 + 1 + 2;
+
+    /// Validates the [LockerParams].
+    pub fn validate(&self) -> Result<()> {
+        invariant!(
+            u64::from(self.early_exit_penalty_bps) <= BPS_DENOMINATOR,
+            InvalidEarlyExitPenalty
+        );
+        Ok(())
+    }
+
+    /// Validates that every lock allowed by the [LockerParams] can be tracked by a [LockerHistory].
+    pub fn validate_history(&self) -> Result<()> {
+        invariant!(
+            self.max_stake_duration <= MAX_HISTORY_STAKE_DURATION,
+            StakeDurationTooLongForHistory
+        );
+        Ok(())
+    }
 }
 
 /// An entry in the [Locker]'s whitelist.
@@ -134,6 +186,216 @@ impl Escrow {
     }
 }
 
+/// Number of seconds in a week. Slope changes of a [LockerHistory] are scheduled at week boundaries.
+pub const SECONDS_PER_WEEK: i64 = 7 * 24 * 60 * 60;
+
+/// Maximum number of [LockerHistory::points].
+pub const MAX_LOCKER_POINTS: usize = 64;
+
+/// Number of weeks of [LockerHistory::slope_changes] which can be scheduled.
+/// Escrows tracked by a [LockerHistory] may not end more than this many weeks in the future.
+pub const MAX_SLOPE_CHANGE_WEEKS: usize = 320;
+
+/// Maximum [LockerParams::max_stake_duration] of a [Locker] with a [LockerHistory], in seconds.
+/// Escrows are tracked as ending at the next week boundary, so a lock may end up to
+/// two week boundaries later than its duration alone.
+pub const MAX_HISTORY_STAKE_DURATION: u64 =
+    (MAX_SLOPE_CHANGE_WEEKS as u64 - 2) * SECONDS_PER_WEEK as u64;
+
+/// The history of the total voting power of the [Escrow]s in a [Locker],
+/// tracked in the style of Curve's veCRV.
+///
+/// The total voting power is a piecewise linear function of time. Each [LockerPoint] records
+/// the function at a point in time, and the slope changes record when [Escrow]s expire.
+/// For this accounting, [Escrow]s are considered to end at the next week boundary,
+/// so the tracked total voting power may slightly exceed the sum of the voting power of all [Escrow]s.
+///
+/// There is no way to backfill the voting power of existing [Escrow]s, so a [LockerHistory]
+/// can only be created for a [Locker] without any locked tokens.
+#[account]
+#[derive(Debug, Default)]
+pub struct LockerHistory {
+    /// The [Locker].
+    pub locker: Pubkey,
+    /// Bump seed.
+    pub bump: u8,
+    /// Points of the total voting power, from oldest to newest.
+    /// Once there are [MAX_LOCKER_POINTS], the oldest point is discarded.
+    pub points: Vec<LockerPoint>,
+    /// The number of locked tokens expiring at each upcoming week boundary,
+    /// indexed by the week number modulo [MAX_SLOPE_CHANGE_WEEKS].
+    pub slope_changes: Vec<u64>,
+}
+
+impl LockerHistory {
+    /// Number of bytes in a [LockerHistory].
+    pub const LEN: usize = PUBKEY_BYTES
+        + 1
+        + 4
+        + LockerPoint::LEN * MAX_LOCKER_POINTS
+        + 4
+        + 8 * MAX_SLOPE_CHANGE_WEEKS;
+}
+
+/// The total voting power of a [Locker] at a point in time, in units of locked token-seconds.
+/// See [LockerParams::calculate_total_voter_power].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LockerPoint {
+    /// The sum over all tracked [Escrow]s of the number of tokens locked multiplied by
+    /// the number of seconds until the [Escrow] ends.
+    pub bias: u128,
+    /// The number of tokens locked in tracked [Escrow]s which have not yet ended.
+    /// This is the rate at which [LockerPoint::bias] decreases each second.
+    pub slope: u64,
+    /// When the point was recorded.
+    pub timestamp: i64,
+}
+
+impl LockerPoint {
+    /// Number of bytes in a [LockerPoint].
+    pub const LEN: usize = 16 + 8 + 8;
+}
+
+/// Authorizes an account to extend the lock of an [Escrow] on behalf of its owner,
+/// via [crate::locked_voter::extend_lock_duration_as_extender].
+#[account]
+#[derive(Copy, Debug, Default)]
+pub struct EscrowExtender {
+    /// The [Escrow].
+    pub escrow: Pubkey,
+    /// The account authorized to extend the lock, e.g. a keeper or a PDA of a program.
+    pub extender: Pubkey,
+    /// Bump seed.
+    pub bump: u8,
+    /// The latest [Escrow::escrow_ends_at] the extender may extend the lock to.
+    pub max_escrow_ends_at: i64,
+}
+
+impl EscrowExtender {
+    /// Number of bytes in an [EscrowExtender].
+    pub const LEN: usize = PUBKEY_BYTES * 2 + 1 + 8;
+}
+
+/// Maximum number of [EscrowHistory::checkpoints].
+pub const MAX_ESCROW_CHECKPOINTS: usize = 32;
+
+/// The lock history of an [Escrow], used to compute its voting power at a past time.
+/// See [LockerParams::snapshot_voting_enabled].
+#[account]
+#[derive(Debug, Default)]
+pub struct EscrowHistory {
+    /// The [Escrow].
+    pub escrow: Pubkey,
+    /// Bump seed.
+    pub bump: u8,
+    /// Checkpoints of the [Escrow], from oldest to newest.
+    /// Once there are [MAX_ESCROW_CHECKPOINTS], the oldest checkpoint is discarded
+    /// if it is no longer retained (see [Locker::snapshot_retention_seconds]),
+    /// and otherwise compacted into the next checkpoint.
+    pub checkpoints: Vec<EscrowCheckpoint>,
+}
+
+impl EscrowHistory {
+    /// Number of bytes in an [EscrowHistory].
+    pub const LEN: usize = PUBKEY_BYTES + 1 + 4 + EscrowCheckpoint::LEN * MAX_ESCROW_CHECKPOINTS;
+
+    /// Records the current lock of the [Escrow], returning false if it is unchanged
+    /// since the latest checkpoint. A checkpoint at the same timestamp as the latest
+    /// checkpoint replaces it.
+    ///
+    /// Once there are [MAX_ESCROW_CHECKPOINTS], the oldest checkpoint is discarded, unless
+    /// it may still be needed for times within `retention_seconds` of `timestamp`.
+    /// In that case, the two oldest checkpoints are compacted into their lesser lock,
+    /// so that recording never fails and the [Escrow] is never credited with more voting power than it had.
+    pub fn record(
+        &mut self,
+        escrow: &Escrow,
+        timestamp: i64,
+        retention_seconds: u64,
+    ) -> Result<bool> {
+        let checkpoint = EscrowCheckpoint {
+            timestamp,
+            amount: escrow.amount,
+            escrow_started_at: escrow.escrow_started_at,
+            escrow_ends_at: escrow.escrow_ends_at,
+        };
+        if let Some(last) = self.checkpoints.last_mut() {
+            if last.is_same_lock(&checkpoint) {
+                return Ok(false);
+            }
+            if last.timestamp == timestamp {
+                *last = checkpoint;
+                return Ok(true);
+            }
+        }
+        if self.checkpoints.len() >= MAX_ESCROW_CHECKPOINTS {
+            // the oldest checkpoint only applies to times before the next checkpoint
+            let retained_since = unwrap_int!(retention_seconds
+                .to_i64()
+                .and_then(|retention_seconds| timestamp.checked_sub(retention_seconds)));
+            let oldest = self.checkpoints.remove(0);
+            let next = unwrap_opt!(self.checkpoints.first_mut(), "escrow history too small");
+            if next.timestamp > retained_since {
+                *next = EscrowCheckpoint {
+                    timestamp: oldest.timestamp,
+                    amount: oldest.amount.min(next.amount),
+                    escrow_started_at: oldest.escrow_started_at.min(next.escrow_started_at),
+                    escrow_ends_at: oldest.escrow_ends_at.min(next.escrow_ends_at),
+                };
+            }
+        }
+        self.checkpoints.push(checkpoint);
+        Ok(true)
+    }
+
+    /// Gets the voting power the [Escrow] had at the given time.
+    /// If there is no checkpoint at or before that time, the [Escrow] is considered to have had no voting power.
+    pub fn voting_power_at_time(&self, locker: &LockerParams, timestamp: i64) -> Option<u64> {
+        match self
+            .checkpoints
+            .iter()
+            .rev()
+            .find(|checkpoint| checkpoint.timestamp <= timestamp)
+        {
+            Some(checkpoint) => locker.calculate_voter_power(
+                &Escrow {
+                    amount: checkpoint.amount,
+                    escrow_started_at: checkpoint.escrow_started_at,
+                    escrow_ends_at: checkpoint.escrow_ends_at,
+                    ..Escrow::default()
+                },
+                timestamp,
+            ),
+            None => Some(0),
+        }
+    }
+}
+
+/// The lock of an [Escrow] at a point in time.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EscrowCheckpoint {
+    /// When the checkpoint was recorded.
+    pub timestamp: i64,
+    /// [Escrow::amount].
+    pub amount: u64,
+    /// [Escrow::escrow_started_at].
+    pub escrow_started_at: i64,
+    /// [Escrow::escrow_ends_at].
+    pub escrow_ends_at: i64,
+}
+
+impl EscrowCheckpoint {
+    /// Number of bytes in an [EscrowCheckpoint].
+    pub const LEN: usize = 8 * 4;
+
+    /// Checks if the two checkpoints record the same lock, regardless of when they were recorded.
+    fn is_same_lock(&self, other: &EscrowCheckpoint) -> bool {
+        self.amount == other.amount
+            && self.escrow_started_at == other.escrow_started_at
+            && self.escrow_ends_at == other.escrow_ends_at
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -335,4 +597,161 @@ mod tests {
         assert_eq!(locker.locked_supply, 0);
         assert_escrow(&locker.params, bob, current_ts, 0.0);
     }
+
+    #[test]
+    fn test_escrow_history_ignores_later_locks() {
+        let locker = &mut Locker {
+            params: LockerParams {
+                max_stake_duration: MAX_TIME as u64,
+                max_stake_vote_multiplier: DEFAULT_STAKE_MULTIPLIER,
+                snapshot_voting_enabled: true,
+                ..LockerParams::default()
+            },
+            ..Locker::default()
+        };
+        let alice = &mut Escrow::default();
+        let history = &mut EscrowHistory::default();
+
+        let mut current_ts = CANONICAL_START_TIME;
+        alice
+            .record_lock_event(locker, DEFAULT_LOCK_AMOUNT, current_ts, current_ts + WEEK)
+            .unwrap();
+        assert!(history.record(alice, current_ts, DAY as u64).unwrap());
+
+        current_ts += HOUR;
+        let activated_at = current_ts;
+        let power_at_activation = alice
+            .voting_power_at_time(&locker.params, activated_at)
+            .unwrap();
+
+        // Alice locks more tokens after the proposal is activated
+        current_ts += HOUR;
+        alice
+            .record_lock_event(locker, DEFAULT_LOCK_AMOUNT, current_ts, current_ts + WEEK)
+            .unwrap();
+        assert!(history.record(alice, current_ts, DAY as u64).unwrap());
+
+        assert_eq!(
+            history
+                .voting_power_at_time(&locker.params, activated_at)
+                .unwrap(),
+            power_at_activation
+        );
+        assert_eq!(
+            history
+                .voting_power_at_time(&locker.params, CANONICAL_START_TIME - 1)
+                .unwrap(),
+            0
+        );
+        assert_eq!(history.checkpoints.len(), 2);
+
+        // recording an unchanged escrow is a no-op
+        current_ts += HOUR;
+        assert!(!history.record(alice, current_ts, DAY as u64).unwrap());
+        assert_eq!(history.checkpoints.len(), 2);
+
+        // the history fills up with lock changes within the retention period
+        let mut amount = alice.amount;
+        while history.checkpoints.len() < MAX_ESCROW_CHECKPOINTS {
+            current_ts += 1;
+            amount += 1;
+            alice.amount = amount;
+            assert!(history.record(alice, current_ts, DAY as u64).unwrap());
+        }
+        let full_history = history.checkpoints.clone();
+
+        // once the retention period has passed, the oldest checkpoint is discarded
+        current_ts = activated_at + HOUR + DAY;
+        alice.amount += 1;
+        assert!(history.record(alice, current_ts, DAY as u64).unwrap());
+        assert_eq!(history.checkpoints.len(), MAX_ESCROW_CHECKPOINTS);
+        assert_eq!(
+            history.checkpoints[..MAX_ESCROW_CHECKPOINTS - 1],
+            full_history[1..]
+        );
+        assert_eq!(
+            history
+                .voting_power_at_time(&locker.params, activated_at)
+                .unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_full_escrow_history_compacts_retained_checkpoints() {
+        let locker = &mut Locker {
+            params: LockerParams {
+                max_stake_duration: MAX_TIME as u64,
+                max_stake_vote_multiplier: DEFAULT_STAKE_MULTIPLIER,
+                snapshot_voting_enabled: true,
+                ..LockerParams::default()
+            },
+            ..Locker::default()
+        };
+        let alice = &mut Escrow::default();
+        let history = &mut EscrowHistory::default();
+
+        let mut current_ts = CANONICAL_START_TIME;
+        alice
+            .record_lock_event(locker, DEFAULT_LOCK_AMOUNT, current_ts, current_ts + WEEK)
+            .unwrap();
+        assert!(history.record(alice, current_ts, DAY as u64).unwrap());
+        let activated_at = current_ts + HOUR;
+        let power_at_activation = alice
+            .voting_power_at_time(&locker.params, activated_at)
+            .unwrap();
+
+        // fill the history with lock changes within the retention period
+        current_ts += 2 * HOUR;
+        while history.checkpoints.len() < MAX_ESCROW_CHECKPOINTS {
+            current_ts += 1;
+            alice
+                .record_lock_event(locker, 1, current_ts, alice.escrow_ends_at)
+                .unwrap();
+            assert!(history.record(alice, current_ts, DAY as u64).unwrap());
+        }
+
+        // further locks compact the oldest checkpoints instead of failing
+        current_ts += 1;
+        alice
+            .record_lock_event(locker, 1, current_ts, alice.escrow_ends_at + WEEK)
+            .unwrap();
+        assert!(history.record(alice, current_ts, DAY as u64).unwrap());
+        assert_eq!(history.checkpoints.len(), MAX_ESCROW_CHECKPOINTS);
+        assert_eq!(
+            history
+                .voting_power_at_time(&locker.params, activated_at)
+                .unwrap(),
+            power_at_activation
+        );
+
+        // Alice exits early with a full history
+        current_ts += 1;
+        let exited_at = current_ts;
+        let before_exit = exited_at - 1;
+        let power_before_exit = history
+            .voting_power_at_time(&locker.params, before_exit)
+            .unwrap();
+        assert!(power_before_exit > 0);
+        assert!(history
+            .record(&Escrow::default(), exited_at, DAY as u64)
+            .unwrap());
+        assert_eq!(history.checkpoints.len(), MAX_ESCROW_CHECKPOINTS);
+        assert_eq!(
+            history
+                .voting_power_at_time(&locker.params, exited_at)
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            history
+                .voting_power_at_time(&locker.params, before_exit)
+                .unwrap(),
+            power_before_exit
+        );
+
+        // compacted checkpoints keep the lesser lock
+        assert_eq!(history.checkpoints[0].timestamp, CANONICAL_START_TIME);
+        assert_eq!(history.checkpoints[0].amount, DEFAULT_LOCK_AMOUNT);
+    }
 }
@@ -51,7 +51,9 @@ pub mod simple_voter {
     }
 
     #[access_control(ctx.accounts.validate())]
-    pub fn activate_proposal(ctx: Context<ActivateProposal>) -> Result<()> {
+    pub fn activate_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ActivateProposal<'info>>,
+    ) -> Result<()> {
         processor::proposer::activate_proposal(ctx)
     }
 
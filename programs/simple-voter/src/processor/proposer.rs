@@ -1,14 +1,17 @@
 use crate::{electorate_seeds, ActivateProposal};
 use anchor_lang::prelude::*;
 
-pub fn activate_proposal(ctx: Context<ActivateProposal>) -> Result<()> {
+pub fn activate_proposal<'info>(
+    ctx: Context<'_, '_, '_, 'info, ActivateProposal<'info>>,
+) -> Result<()> {
     let seeds: &[&[&[u8]]] = electorate_seeds!(ctx.accounts.electorate);
     govern::cpi::activate_proposal(
         CpiContext::new(
             ctx.accounts.govern_program.to_account_info(),
             ctx.accounts.to_activate_proposal_accounts(),
         )
-        .with_signer(seeds),
+        .with_signer(seeds)
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
     )
 }
 
@@ -82,6 +82,14 @@ export const DEFAULT_GOVERNANCE_PARAMETERS: GovernanceParameters = {
   quorumVotes: DEFAULT_QUORUM_VOTES,
   votingDelay: DEFAULT_VOTE_DELAY,
   votingPeriod: DEFAULT_VOTE_PERIOD,
+  gracePeriodSeconds: new BN(0),
+  approvalThresholdBps: 0,
+  quorumBps: 0,
+  voteExtensionSeconds: new BN(0),
+  maxVoteExtensionSeconds: new BN(0),
+  earlyQueueEnabled: false,
+  maxActiveProposalsPerProposer: new BN(0),
+  paramsChangeDelaySeconds: new BN(0),
 };
 
 /**
@@ -96,4 +104,6 @@ export const DEFAULT_LOCKER_PARAMS: LockerParams = {
   maxStakeDuration: new BN(5).mul(ONE_YEAR),
   maxStakeVoteMultiplier: 10,
   whitelistEnabled: false,
+  snapshotVotingEnabled: false,
+  earlyExitPenaltyBps: 0,
 };
@@ -12,6 +12,7 @@ export type GovernTypes = AnchorTypes<
     proposal: ProposalData;
     vote: VoteData;
     proposalMeta: ProposalMetaData;
+    voteRationale: VoteRationaleData;
   },
   {
     ProposalInstruction: ProposalInstruction;
@@ -24,6 +25,9 @@ type Accounts = GovernTypes["Accounts"];
 export type GovernorData = Accounts["Governor"];
 export type ProposalData = Accounts["Proposal"];
 export type VoteData = Accounts["Vote"];
+export type VoteRationaleData = Accounts["VoteRationale"] & {
+  uri: string;
+};
 export type ProposalMetaData = Accounts["ProposalMeta"] & {
   title: string;
   descriptionLink: string;
@@ -31,6 +35,7 @@ export type ProposalMetaData = Accounts["ProposalMeta"] & {
 
 export type GovernanceParameters =
   GovernTypes["Defined"]["GovernanceParameters"];
+export type OverrideParameters = GovernTypes["Defined"]["OverrideParameters"];
 export type ProposalInstruction =
   GovernTypes["Defined"]["ProposalInstruction"] & {
     keys: AccountMeta[];
@@ -4,24 +4,29 @@ import {
   GOKI_CODERS,
 } from "@gokiprotocol/client";
 import { TransactionEnvelope } from "@saberhq/solana-contrib";
-import { u64 } from "@saberhq/token-utils";
-import type { PublicKey, TransactionInstruction } from "@solana/web3.js";
-import { SystemProgram } from "@solana/web3.js";
+import { getATAAddress, TOKEN_PROGRAM_ID, u64 } from "@saberhq/token-utils";
+import type { AccountMeta, TransactionInstruction } from "@solana/web3.js";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
 import type BN from "bn.js";
 
 import type {
   GovernanceParameters,
   GovernorData,
+  OverrideParameters,
   ProposalData,
   ProposalInstruction,
   ProposalMetaData,
+  VoteRationaleData,
 } from "../../programs/govern";
 import type { TribecaSDK } from "../../sdk";
 import type { PendingProposal } from "../simpleVoter/types";
 import {
+  findActiveProposalCounterAddress,
+  findParameterOverrideAddress,
   findProposalAddress,
   findProposalMetaAddress,
   findVoteAddress,
+  findVoteRationaleAddress,
 } from "./pda";
 
 /**
@@ -70,6 +75,11 @@ export class GovernorWrapper {
     return await this.program.account.proposalMeta.fetch(key);
   }
 
+  async fetchVoteRationale(voteKey: PublicKey): Promise<VoteRationaleData> {
+    const [key] = await findVoteRationaleAddress(voteKey);
+    return await this.program.account.voteRationale.fetch(key);
+  }
+
   /**
    * Creates a ProposalMeta for a proposal.
    * Only the Proposer may call this.
@@ -105,6 +115,40 @@ export class GovernorWrapper {
     return this.provider.newTX([ix]);
   }
 
+  /**
+   * Updates the ProposalMeta of a proposal.
+   * Only the Proposer may call this, while the proposal is a Draft.
+   *
+   * @returns
+   */
+  async updateProposalMeta({
+    proposal,
+    proposer = this.sdk.provider.wallet.publicKey,
+    title,
+    descriptionLink,
+  }: {
+    proposal: PublicKey;
+    proposer?: PublicKey;
+    title: string;
+    descriptionLink: string;
+  }): Promise<TransactionEnvelope> {
+    const [proposalMetaKey] = await findProposalMetaAddress(proposal);
+    const ix = this.sdk.programs.Govern.instruction.updateProposalMeta(
+      title,
+      descriptionLink,
+      {
+        accounts: {
+          proposal,
+          proposer,
+          proposalMeta: proposalMetaKey,
+          payer: this.provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        },
+      }
+    );
+    return this.provider.newTX([ix]);
+  }
+
   /**
    * Creates a new Proposal.
    * @returns
@@ -133,6 +177,10 @@ export class GovernorWrapper {
           payer: provider.wallet.publicKey,
           systemProgram: SystemProgram.programId,
         },
+        remainingAccounts: [
+          ...(await this.findProposalDepositAccounts(proposer)),
+          ...(await this.findParameterOverrideAccounts(instructions)),
+        ],
       })
     );
 
@@ -143,6 +191,349 @@ export class GovernorWrapper {
     };
   }
 
+  /**
+   * Creates an empty Proposal with room for `capacity` bytes of instructions.
+   * Instructions are added via {@link appendProposalInstructions}, after
+   * which the Proposal must be sealed via {@link sealProposal}.
+   * @returns
+   */
+  async createStagedProposal({
+    proposer = this.sdk.provider.wallet.publicKey,
+    capacity,
+  }: {
+    proposer?: PublicKey;
+    capacity: number;
+  }): Promise<PendingProposal> {
+    const { provider } = this.sdk;
+
+    const governorData = await this.reload();
+    const index = new u64(governorData.proposalCount);
+    const [proposal, bump] = await findProposalAddress(this.governorKey, index);
+
+    const ix = this.program.instruction.createStagedProposal(bump, capacity, {
+      accounts: {
+        governor: this.governorKey,
+        proposal,
+        proposer,
+        payer: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      },
+      remainingAccounts: await this.findProposalDepositAccounts(proposer),
+    });
+
+    return {
+      proposal,
+      index,
+      tx: this.provider.newTX([ix]),
+    };
+  }
+
+  /**
+   * Appends instructions to an unsealed Proposal.
+   * Only the Proposer may call this.
+   * @returns
+   */
+  appendProposalInstructions({
+    proposal,
+    proposer = this.sdk.provider.wallet.publicKey,
+    instructions,
+  }: {
+    proposal: PublicKey;
+    proposer?: PublicKey;
+    instructions: ProposalInstruction[];
+  }): TransactionEnvelope {
+    return this.provider.newTX([
+      this.program.instruction.appendProposalInstructions(instructions, {
+        accounts: {
+          proposal,
+          proposer,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Seals a Proposal, allowing it to be activated.
+   * Only the Proposer may call this.
+   * @returns
+   */
+  async sealProposal({
+    proposal,
+    proposer = this.sdk.provider.wallet.publicKey,
+  }: {
+    proposal: PublicKey;
+    proposer?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const { instructions } = await this.fetchProposalByKey(proposal);
+    return this.provider.newTX([
+      this.program.instruction.sealProposal({
+        accounts: {
+          governor: this.governorKey,
+          proposal,
+          proposer,
+        },
+        remainingAccounts: await this.findParameterOverrideAccounts(
+          instructions
+        ),
+      }),
+    ]);
+  }
+
+  /**
+   * Replaces or appends to the instructions of a Draft Proposal.
+   * Only the Proposer may call this.
+   * @returns
+   */
+  async amendProposalInstructions({
+    proposal,
+    proposer = this.sdk.provider.wallet.publicKey,
+    instructions,
+    append,
+  }: {
+    proposal: PublicKey;
+    proposer?: PublicKey;
+    instructions: ProposalInstruction[];
+    append: boolean;
+  }): Promise<TransactionEnvelope> {
+    const newInstructions = append
+      ? [
+          ...(await this.fetchProposalByKey(proposal)).instructions,
+          ...instructions,
+        ]
+      : instructions;
+    return this.provider.newTX([
+      this.program.instruction.amendProposalInstructions(instructions, append, {
+        accounts: {
+          governor: this.governorKey,
+          proposal,
+          proposer,
+          payer: this.provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        },
+        remainingAccounts: await this.findParameterOverrideAccounts(
+          newInstructions
+        ),
+      }),
+    ]);
+  }
+
+  /**
+   * Activates a Proposal.
+   * Only the electorate may call this.
+   * The proposer's ActiveProposalCounter must be provided if the Governor
+   * limits the number of active proposals per proposer.
+   * @returns
+   */
+  activateProposal({
+    proposal,
+    electorate,
+    activeProposalCounter,
+  }: {
+    proposal: PublicKey;
+    electorate: PublicKey;
+    activeProposalCounter?: PublicKey;
+  }): TransactionEnvelope {
+    return this.provider.newTX([
+      this.program.instruction.activateProposal({
+        accounts: {
+          governor: this.governorKey,
+          proposal,
+          electorate,
+        },
+        remainingAccounts: activeProposalCounter
+          ? [
+              {
+                pubkey: activeProposalCounter,
+                isSigner: false,
+                isWritable: true,
+              },
+            ]
+          : [],
+      }),
+    ]);
+  }
+
+  /**
+   * Creates the ActiveProposalCounter of a proposer.
+   * @returns
+   */
+  async createActiveProposalCounter({
+    proposer,
+    payer = this.sdk.provider.wallet.publicKey,
+  }: {
+    proposer: PublicKey;
+    payer?: PublicKey;
+  }): Promise<{ activeProposalCounter: PublicKey; tx: TransactionEnvelope }> {
+    const [activeProposalCounter, bump] =
+      await findActiveProposalCounterAddress(this.governorKey, proposer);
+    return {
+      activeProposalCounter,
+      tx: this.provider.newTX([
+        this.program.instruction.createActiveProposalCounter(bump, proposer, {
+          accounts: {
+            governor: this.governorKey,
+            activeProposalCounter,
+            payer,
+            systemProgram: SystemProgram.programId,
+          },
+        }),
+      ]),
+    };
+  }
+
+  /**
+   * Releases a Proposal which is no longer active from its proposer's ActiveProposalCounter.
+   * @returns
+   */
+  async releaseActiveProposal(
+    proposal: PublicKey
+  ): Promise<TransactionEnvelope> {
+    const { proposer } = await this.fetchProposalByKey(proposal);
+    const [activeProposalCounter] = await findActiveProposalCounterAddress(
+      this.governorKey,
+      proposer
+    );
+    return this.provider.newTX([
+      this.program.instruction.releaseActiveProposal({
+        accounts: {
+          proposal,
+          activeProposalCounter,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Gets the accounts required to pay the proposal deposit, if the Governor requires one.
+   */
+  async findProposalDepositAccounts(
+    proposer: PublicKey
+  ): Promise<AccountMeta[]> {
+    const { depositMint, depositAmount } = await this.data();
+    if (depositAmount.isZero()) {
+      return [];
+    }
+    return [
+      {
+        pubkey: await getATAAddress({ mint: depositMint, owner: proposer }),
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: await getATAAddress({
+          mint: depositMint,
+          owner: this.governorKey,
+        }),
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+  }
+
+  /**
+   * Gets the ParameterOverride accounts of each program invoked by the instructions,
+   * in order of first invocation.
+   */
+  async findParameterOverrideAccounts(
+    instructions: ProposalInstruction[]
+  ): Promise<AccountMeta[]> {
+    return await this.findProgramParameterOverrideAccounts(
+      instructions.map(({ programId }) => programId)
+    );
+  }
+
+  /**
+   * Gets the ParameterOverride accounts of each of the invoked programs,
+   * in order of first invocation.
+   */
+  async findProgramParameterOverrideAccounts(
+    invokedProgramIds: PublicKey[]
+  ): Promise<AccountMeta[]> {
+    const programIds: PublicKey[] = [];
+    invokedProgramIds.forEach((programId) => {
+      if (!programIds.some((p) => p.equals(programId))) {
+        programIds.push(programId);
+      }
+    });
+    return await Promise.all(
+      programIds.map(async (programId) => {
+        const [key] = await findParameterOverrideAddress(
+          this.governorKey,
+          programId
+        );
+        return { pubkey: key, isSigner: false, isWritable: false };
+      })
+    );
+  }
+
+  /**
+   * Creates a new Proposal which only stores the hash of its instructions.
+   * The instructions are revealed via {@link queueCommittedProposal}.
+   * @returns
+   */
+  async createCommittedProposal({
+    proposer = this.sdk.provider.wallet.publicKey,
+    instructionsHash,
+    invokedProgramIds,
+  }: {
+    proposer?: PublicKey;
+    instructionsHash: Buffer;
+    invokedProgramIds: PublicKey[];
+  }): Promise<PendingProposal> {
+    const { provider } = this.sdk;
+
+    const governorData = await this.reload();
+    const index = new u64(governorData.proposalCount);
+    const [proposal, bump] = await findProposalAddress(this.governorKey, index);
+
+    const ix = this.program.instruction.createCommittedProposal(
+      bump,
+      [...instructionsHash],
+      invokedProgramIds,
+      {
+        accounts: {
+          governor: this.governorKey,
+          proposal,
+          proposer,
+          payer: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        },
+        remainingAccounts: [
+          ...(await this.findProposalDepositAccounts(proposer)),
+          ...(await this.findProgramParameterOverrideAccounts(
+            invokedProgramIds
+          )),
+        ],
+      }
+    );
+
+    return {
+      proposal,
+      index,
+      tx: this.provider.newTX([ix]),
+    };
+  }
+
+  /**
+   * Finds the address and bump of the next Smart Wallet transaction.
+   */
+  private async findNextTransactionAddress(): Promise<[PublicKey, number]> {
+    const { smartWallet } = await this.data();
+    const smartWalletDataRaw =
+      await this.program.provider.connection.getAccountInfo(smartWallet);
+    if (!smartWalletDataRaw) {
+      throw new Error("smart wallet not found");
+    }
+    const smartWalletData = GOKI_CODERS.SmartWallet.accountParsers.smartWallet(
+      smartWalletDataRaw.data
+    );
+    return await findTransactionAddress(
+      smartWallet,
+      smartWalletData.numTransactions.toNumber()
+    );
+  }
+
   /**
    * Queues a Proposal for execution by the Smart Wallet.
    * @returns
@@ -159,20 +550,7 @@ export class GovernorWrapper {
     const governorData = await this.data();
 
     const [proposal] = await findProposalAddress(this.governorKey, index);
-    const smartWalletDataRaw =
-      await this.program.provider.connection.getAccountInfo(
-        governorData.smartWallet
-      );
-    if (!smartWalletDataRaw) {
-      throw new Error("smart wallet not found");
-    }
-    const smartWalletData = GOKI_CODERS.SmartWallet.accountParsers.smartWallet(
-      smartWalletDataRaw.data
-    );
-    const [txKey, txBump] = await findTransactionAddress(
-      governorData.smartWallet,
-      smartWalletData.numTransactions.toNumber()
-    );
+    const [txKey, txBump] = await this.findNextTransactionAddress();
     return new TransactionEnvelope(this.sdk.provider, [
       this.program.instruction.queueProposal(txBump, {
         accounts: {
@@ -188,6 +566,44 @@ export class GovernorWrapper {
     ]);
   }
 
+  /**
+   * Queues a committed Proposal for execution by the Smart Wallet,
+   * revealing its instructions.
+   * @returns
+   */
+  async queueCommittedProposal({
+    index,
+    instructions,
+    smartWalletProgram = GOKI_ADDRESSES.SmartWallet,
+    payer = this.provider.wallet.publicKey,
+  }: {
+    index: BN;
+    instructions: ProposalInstruction[];
+    smartWalletProgram?: PublicKey;
+    payer?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const governorData = await this.data();
+
+    const [proposal] = await findProposalAddress(this.governorKey, index);
+    const [txKey, txBump] = await this.findNextTransactionAddress();
+    return new TransactionEnvelope(this.sdk.provider, [
+      this.program.instruction.queueCommittedProposal(txBump, instructions, {
+        accounts: {
+          governor: this.governorKey,
+          proposal,
+          smartWallet: governorData.smartWallet,
+          smartWalletProgram,
+          transaction: txKey,
+          payer,
+          systemProgram: SystemProgram.programId,
+        },
+        remainingAccounts: await this.findParameterOverrideAccounts(
+          instructions
+        ),
+      }),
+    ]);
+  }
+
   /**
    * Cancel a new Proposal.
    * @returns
@@ -210,6 +626,38 @@ export class GovernorWrapper {
     ]);
   }
 
+  /**
+   * Vetoes a Proposal.
+   * Only the guardian may call this.
+   * @returns
+   */
+  async vetoProposal({
+    proposal,
+    guardian = this.sdk.provider.wallet.publicKey,
+  }: {
+    proposal: PublicKey;
+    guardian?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const { smartWallet } = await this.data();
+    const { queuedAt, queuedTransaction } = await this.fetchProposalByKey(
+      proposal
+    );
+    return this.provider.newTX([
+      this.program.instruction.vetoProposal({
+        accounts: {
+          governor: this.governorKey,
+          proposal,
+          guardian,
+          smartWallet,
+          smartWalletProgram: GOKI_ADDRESSES.SmartWallet,
+        },
+        remainingAccounts: queuedAt.isZero()
+          ? []
+          : [{ pubkey: queuedTransaction, isSigner: false, isWritable: true }],
+      }),
+    ]);
+  }
+
   async getOrCreateVote({
     proposal,
     voter = this.sdk.provider.wallet.publicKey,
@@ -266,6 +714,287 @@ export class GovernorWrapper {
     });
   }
 
+  /**
+   * Sets the side and weight of a Vote, creating the Vote if needed.
+   * Only the electorate may call this.
+   * @returns
+   */
+  async setVote({
+    proposal,
+    voter = this.sdk.provider.wallet.publicKey,
+    side,
+    weight,
+    electorate,
+  }: {
+    proposal: PublicKey;
+    voter?: PublicKey;
+    side: number;
+    weight: BN;
+    electorate: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const { voteKey, instruction } = await this.getOrCreateVote({
+      proposal,
+      voter,
+    });
+    return this.provider.newTX([
+      instruction,
+      this.program.instruction.setVote(side, weight, {
+        accounts: {
+          governor: this.governorKey,
+          proposal,
+          vote: voteKey,
+          electorate,
+        },
+      }),
+    ]);
+  }
+
+  async createParameterOverrideIx(
+    targetProgramId: PublicKey,
+    params: OverrideParameters
+  ): Promise<TransactionInstruction> {
+    const { smartWallet } = await this.data();
+    const [parameterOverride, bump] = await findParameterOverrideAddress(
+      this.governorKey,
+      targetProgramId
+    );
+    return this.program.instruction.createParameterOverride(
+      bump,
+      targetProgramId,
+      params,
+      {
+        accounts: {
+          governor: this.governorKey,
+          parameterOverride,
+          smartWallet,
+          payer: this.provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        },
+      }
+    );
+  }
+
+  /**
+   * Creates an empty VoteRationale for a Vote.
+   * @returns
+   */
+  async createVoteRationale({
+    vote,
+    payer = this.sdk.provider.wallet.publicKey,
+  }: {
+    vote: PublicKey;
+    payer?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const [voteRationale, bump] = await findVoteRationaleAddress(vote);
+    return this.provider.newTX([
+      this.program.instruction.createVoteRationale(bump, {
+        accounts: {
+          vote,
+          voteRationale,
+          payer,
+          systemProgram: SystemProgram.programId,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Sets the rationale of a Vote.
+   * Only the voter or the electorate may call this.
+   * @returns
+   */
+  async setVoteRationale({
+    proposal,
+    vote,
+    contentHash,
+    uri,
+    authority = this.sdk.provider.wallet.publicKey,
+  }: {
+    proposal: PublicKey;
+    vote: PublicKey;
+    contentHash: Buffer;
+    uri: string;
+    authority?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const [voteRationale] = await findVoteRationaleAddress(vote);
+    return this.provider.newTX([
+      this.program.instruction.setVoteRationale([...contentHash], uri, {
+        accounts: {
+          governor: this.governorKey,
+          proposal,
+          vote,
+          voteRationale,
+          authority,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Refunds or slashes the deposit of a Proposal.
+   * @returns
+   */
+  async settleProposalDeposit(
+    proposal: PublicKey
+  ): Promise<TransactionEnvelope> {
+    const { smartWallet } = await this.data();
+    const { proposer, depositMint } = await this.fetchProposalByKey(proposal);
+    return this.provider.newTX([
+      this.program.instruction.settleProposalDeposit({
+        accounts: {
+          governor: this.governorKey,
+          proposal,
+          depositVault: await getATAAddress({
+            mint: depositMint,
+            owner: this.governorKey,
+          }),
+          proposerTokens: await getATAAddress({
+            mint: depositMint,
+            owner: proposer,
+          }),
+          treasuryTokens: await getATAAddress({
+            mint: depositMint,
+            owner: smartWallet,
+          }),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Gets the queued transaction of a Proposal, which must be provided when closing
+   * the Proposal and its accounts. Any account may be provided if the Proposal
+   * was not queued or has been closed.
+   */
+  private async findQueuedTransaction(proposal: PublicKey): Promise<PublicKey> {
+    const proposalData = await this.program.account.proposal.fetchNullable(
+      proposal
+    );
+    return proposalData?.queuedTransaction ?? PublicKey.default;
+  }
+
+  /**
+   * Closes a final Proposal, refunding its rent to the payer.
+   * @returns
+   */
+  async closeProposal(proposal: PublicKey): Promise<TransactionEnvelope> {
+    const { payer, queuedTransaction } = await this.fetchProposalByKey(
+      proposal
+    );
+    return this.provider.newTX([
+      this.program.instruction.closeProposal({
+        accounts: {
+          proposal,
+          transaction: queuedTransaction,
+          payer,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Closes a Vote on a final Proposal, refunding its rent to the payer.
+   * @returns
+   */
+  async closeVote({
+    proposal,
+    voter = this.sdk.provider.wallet.publicKey,
+  }: {
+    proposal: PublicKey;
+    voter?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const [vote] = await findVoteAddress(proposal, voter);
+    const { payer } = await this.program.account.vote.fetch(vote);
+    return this.provider.newTX([
+      this.program.instruction.closeVote({
+        accounts: {
+          proposal,
+          transaction: await this.findQueuedTransaction(proposal),
+          vote,
+          payer,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Closes the ProposalMeta of a final Proposal, refunding its rent to the payer.
+   * @returns
+   */
+  async closeProposalMeta(proposal: PublicKey): Promise<TransactionEnvelope> {
+    const [proposalMeta] = await findProposalMetaAddress(proposal);
+    const { payer } = await this.fetchProposalMeta(proposal);
+    return this.provider.newTX([
+      this.program.instruction.closeProposalMeta({
+        accounts: {
+          proposal,
+          transaction: await this.findQueuedTransaction(proposal),
+          proposalMeta,
+          payer,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Closes the VoteRationale of a Vote on a final Proposal, refunding its rent to the payer.
+   * @returns
+   */
+  async closeVoteRationale({
+    proposal,
+    voter = this.sdk.provider.wallet.publicKey,
+  }: {
+    proposal: PublicKey;
+    voter?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const [vote] = await findVoteAddress(proposal, voter);
+    const [voteRationale] = await findVoteRationaleAddress(vote);
+    const { payer } = await this.fetchVoteRationale(vote);
+    return this.provider.newTX([
+      this.program.instruction.closeVoteRationale({
+        accounts: {
+          proposal,
+          transaction: await this.findQueuedTransaction(proposal),
+          vote,
+          voteRationale,
+          payer,
+        },
+      }),
+    ]);
+  }
+
+  async setProposalDepositIx(
+    depositMint: PublicKey,
+    depositAmount: BN
+  ): Promise<TransactionInstruction> {
+    const { smartWallet } = await this.data();
+    return this.program.instruction.setProposalDeposit(
+      depositMint,
+      depositAmount,
+      {
+        accounts: {
+          governor: this.governorKey,
+          smartWallet,
+          depositVault: await getATAAddress({
+            mint: depositMint,
+            owner: this.governorKey,
+          }),
+        },
+      }
+    );
+  }
+
+  async setGuardianIx(newGuardian: PublicKey): Promise<TransactionInstruction> {
+    const { smartWallet } = await this.data();
+    return this.program.instruction.setGuardian(newGuardian, {
+      accounts: {
+        governor: this.governorKey,
+        smartWallet,
+      },
+    });
+  }
+
   async setGovernanceParamsIx(
     newParams: GovernanceParameters
   ): Promise<TransactionInstruction> {
@@ -35,6 +35,23 @@ export const findProposalAddress = async (
   );
 };
 
+/**
+ * Finds the PDA of a ParameterOverride.
+ */
+export const findParameterOverrideAddress = async (
+  governorKey: PublicKey,
+  programId: PublicKey
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddress(
+    [
+      utils.bytes.utf8.encode("TribecaParameterOverride"),
+      governorKey.toBuffer(),
+      programId.toBuffer(),
+    ],
+    TRIBECA_ADDRESSES.Govern
+  );
+};
+
 /**
  * Finds the PDA of a Vote.
  * @param proposalKey
@@ -68,3 +85,48 @@ export const findProposalMetaAddress = async (
     TRIBECA_ADDRESSES.Govern
   );
 };
+
+/**
+ * Finds the address of a VoteRationale.
+ * @param voteKey
+ * @returns
+ */
+export const findVoteRationaleAddress = async (
+  voteKey: PublicKey
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddress(
+    [utils.bytes.utf8.encode("TribecaVoteRationale"), voteKey.toBuffer()],
+    TRIBECA_ADDRESSES.Govern
+  );
+};
+
+/**
+ * Finds the address of the OptimisticConfig of a Governor.
+ * @param governorKey
+ * @returns
+ */
+export const findOptimisticConfigAddress = async (
+  governorKey: PublicKey
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddress(
+    [
+      utils.bytes.utf8.encode("TribecaOptimisticConfig"),
+      governorKey.toBuffer(),
+    ],
+    TRIBECA_ADDRESSES.Govern
+  );
+};
+
+export const findActiveProposalCounterAddress = async (
+  governorKey: PublicKey,
+  proposer: PublicKey
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddress(
+    [
+      utils.bytes.utf8.encode("TribecaActiveProposalCounter"),
+      governorKey.toBuffer(),
+      proposer.toBuffer(),
+    ],
+    TRIBECA_ADDRESSES.Govern
+  );
+};
@@ -1,7 +1,64 @@
 import BN from "bn.js";
 
 import type { ProposalData } from "../../programs/govern";
-import { ProposalState } from "./types";
+import { ProposalKind, ProposalState } from "./types";
+
+/**
+ * Gets the index of the winning option of a multiple-choice proposal,
+ * or null if no option has votes or there is a tie.
+ */
+export const getWinningOption = (proposalData: ProposalData): number | null => {
+  const votes = proposalData.options.map((option) => option.votes);
+  const maxVotes = votes.reduce((max, v) => (v.gt(max) ? v : max), new BN(0));
+  const winners = votes.filter((v) => v.eq(maxVotes));
+  if (maxVotes.isZero() || winners.length > 1) {
+    return null;
+  }
+  return votes.findIndex((v) => v.eq(maxVotes));
+};
+
+/**
+ * Checks if a proposal would pass if voting ended with its current votes.
+ */
+export const isProposalPassing = (proposalData: ProposalData): boolean => {
+  if (proposalData.kind === ProposalKind.Optimistic) {
+    return proposalData.againstVotes.lte(proposalData.vetoVotes);
+  }
+  const totalVotes = proposalData.options.reduce(
+    (sum, option) => sum.add(option.votes),
+    proposalData.forVotes
+      .add(proposalData.abstainVotes)
+      .add(proposalData.againstVotes)
+  );
+  const quorumVotes =
+    proposalData.quorumBps === 0
+      ? proposalData.quorumVotes
+      : BN.max(
+          proposalData.quorumVotes,
+          proposalData.totalVotingPower
+            .mul(new BN(proposalData.quorumBps))
+            .div(new BN(10_000))
+        );
+  if (totalVotes.lt(quorumVotes)) {
+    return false;
+  }
+  if (proposalData.kind === ProposalKind.MultipleChoice) {
+    return getWinningOption(proposalData) !== null;
+  }
+  if (proposalData.approvalThresholdBps === 0) {
+    return proposalData.forVotes.gt(proposalData.againstVotes);
+  }
+  return (
+    proposalData.forVotes.gt(new BN(0)) &&
+    proposalData.forVotes
+      .mul(new BN(10_000))
+      .gte(
+        proposalData.forVotes
+          .add(proposalData.againstVotes)
+          .mul(new BN(proposalData.approvalThresholdBps))
+      )
+  );
+};
 
 /**
  * Gets the state of a proposal.
@@ -16,20 +73,23 @@ export const getProposalState = ({
 }): ProposalState => {
   if (proposalData.canceledAt.gt(new BN(0))) {
     return ProposalState.Canceled;
+  } else if (proposalData.vetoedAt.gt(new BN(0))) {
+    return ProposalState.Vetoed;
   } else if (proposalData.activatedAt.eq(new BN(0))) {
     return ProposalState.Draft;
   } else if (proposalData.votingEndsAt.gte(new BN(currentTimeSeconds))) {
     return ProposalState.Active;
-  } else if (
-    proposalData.forVotes.lte(proposalData.againstVotes) ||
-    proposalData.forVotes
-      .add(proposalData.abstainVotes)
-      .add(proposalData.againstVotes)
-      .lt(proposalData.quorumVotes)
-  ) {
+  } else if (!isProposalPassing(proposalData)) {
     return ProposalState.Defeated;
   } else if (proposalData.queuedAt.gt(new BN(0))) {
     return ProposalState.Queued;
+  } else if (
+    proposalData.gracePeriodSeconds.gt(new BN(0)) &&
+    proposalData.votingEndsAt
+      .add(proposalData.gracePeriodSeconds)
+      .lte(new BN(currentTimeSeconds))
+  ) {
+    return ProposalState.Expired;
   }
   return ProposalState.Succeeded;
 };
@@ -50,6 +50,33 @@ export enum ProposalState {
    * A succeeded proposal may be [ProposalState::Queued] into the [SmartWallet].
    */
   Queued,
+  /*
+   * A succeeded proposal that was not queued within the grace period is [ProposalState::Expired].
+   * It may no longer be queued.
+   */
+  Expired,
+  /*
+   * A proposal that has not yet been executed may be [ProposalState::Vetoed] by the guardian.
+   */
+  Vetoed,
+}
+
+/**
+ * Kind of a proposal.
+ */
+export enum ProposalKind {
+  /**
+   * A proposal voted For, Against, or Abstain.
+   */
+  Standard = 0,
+  /**
+   * A proposal with multiple options, of which the option with the most votes wins.
+   */
+  MultipleChoice = 1,
+  /**
+   * A proposal which succeeds unless more than `vetoVotes` votes are against it.
+   */
+  Optimistic = 2,
 }
 
 /**
@@ -62,6 +89,8 @@ export const PROPOSAL_STATE_LABELS: { [K in ProposalState]: string } = {
   [ProposalState.Defeated]: "Defeated",
   [ProposalState.Succeeded]: "Succeeded",
   [ProposalState.Queued]: "Queued",
+  [ProposalState.Expired]: "Expired",
+  [ProposalState.Vetoed]: "Vetoed",
 } as const;
 
 /**
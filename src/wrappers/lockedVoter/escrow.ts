@@ -13,6 +13,11 @@ import { TRIBECA_ADDRESSES } from "../../constants";
 import type { EscrowData, LockerData } from "../../programs/lockedVoter";
 import type { TribecaSDK } from "../../sdk";
 import { findVoteAddress } from "../govern/pda";
+import {
+  findEscrowExtenderAddress,
+  findEscrowHistoryAddress,
+  findLockerHistoryAddress,
+} from "./pda";
 import type { VoteSide } from "../govern/types";
 
 export class VoteEscrow {
@@ -200,6 +205,134 @@ export class VoteEscrow {
     ]);
   }
 
+  /**
+   * Builds the remaining accounts of a lock change on the escrow.
+   * @returns
+   */
+  private async lockChangeRemainingAccounts() {
+    const lockerData = await this.lockerData();
+    const remainingAccounts = [];
+    if (lockerData.params.snapshotVotingEnabled) {
+      const [escrowHistory] = await findEscrowHistoryAddress(this.escrowKey);
+      remainingAccounts.push({
+        pubkey: escrowHistory,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+    if (lockerData.historyEnabled) {
+      const [lockerHistory] = await findLockerHistoryAddress(this.locker);
+      remainingAccounts.push({
+        pubkey: lockerHistory,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+    return remainingAccounts;
+  }
+
+  /**
+   * Extends the lockup of the escrow to `duration` seconds from now.
+   * @returns
+   */
+  async extendLockDuration(duration: BN): Promise<TransactionEnvelope> {
+    const escrowData = await this.data();
+    return this.provider.newTX([
+      this.lockerProgram.instruction.extendLockDuration(duration, {
+        accounts: {
+          locker: this.locker,
+          escrow: this.escrowKey,
+          escrowOwner: escrowData.owner,
+        },
+        remainingAccounts: await this.lockChangeRemainingAccounts(),
+      }),
+    ]);
+  }
+
+  /**
+   * Extends the lockup of the escrow to `duration` seconds from now,
+   * as an extender approved by the owner.
+   * @returns
+   */
+  async extendLockDurationAsExtender({
+    duration,
+    extender = this.provider.wallet.publicKey,
+  }: {
+    duration: BN;
+    extender?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const [escrowExtender] = await findEscrowExtenderAddress(
+      this.escrowKey,
+      extender
+    );
+    return this.provider.newTX([
+      this.lockerProgram.instruction.extendLockDurationAsExtender(duration, {
+        accounts: {
+          locker: this.locker,
+          escrow: this.escrowKey,
+          escrowExtender,
+          extender,
+        },
+        remainingAccounts: await this.lockChangeRemainingAccounts(),
+      }),
+    ]);
+  }
+
+  /**
+   * Authorizes an account to extend the lockup of the escrow,
+   * up to `maxEscrowEndsAt`.
+   * @returns
+   */
+  async approveExtender({
+    extender,
+    maxEscrowEndsAt,
+  }: {
+    extender: PublicKey;
+    maxEscrowEndsAt: BN;
+  }): Promise<TransactionEnvelope> {
+    const [escrowExtender, bump] = await findEscrowExtenderAddress(
+      this.escrowKey,
+      extender
+    );
+    return this.provider.newTX([
+      this.lockerProgram.instruction.approveEscrowExtender(
+        bump,
+        maxEscrowEndsAt,
+        {
+          accounts: {
+            escrow: this.escrowKey,
+            escrowExtender,
+            extender,
+            escrowOwner: this.owner,
+            payer: this.provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          },
+        }
+      ),
+    ]);
+  }
+
+  /**
+   * Revokes the authorization of an extender of the escrow.
+   * @returns
+   */
+  async revokeExtender(extender: PublicKey): Promise<TransactionEnvelope> {
+    const [escrowExtender] = await findEscrowExtenderAddress(
+      this.escrowKey,
+      extender
+    );
+    return this.provider.newTX([
+      this.lockerProgram.instruction.revokeEscrowExtender({
+        accounts: {
+          escrow: this.escrowKey,
+          escrowExtender,
+          escrowOwner: this.owner,
+          payer: this.provider.wallet.publicKey,
+        },
+      }),
+    ]);
+  }
+
   /**
    * Exits the escrow.
    * @returns
@@ -227,4 +360,87 @@ export class VoteEscrow {
       }),
     ]);
   }
+
+  /**
+   * Withdraws tokens from an unlocked escrow, leaving the escrow open.
+   * @returns
+   */
+  async withdraw(amount: BN): Promise<TransactionEnvelope> {
+    const lockerData = await this.lockerData();
+    const escrowData = await this.data();
+    const destinationTokens = await getOrCreateATA({
+      provider: this.provider,
+      mint: lockerData.tokenMint,
+      owner: escrowData.owner,
+    });
+    return this.provider.newTX([
+      destinationTokens.instruction,
+      this.lockerProgram.instruction.withdraw(amount, {
+        accounts: {
+          locker: this.locker,
+          escrow: this.escrowKey,
+          escrowOwner: escrowData.owner,
+          escrowTokens: escrowData.tokens,
+          destinationTokens: destinationTokens.address,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Exits the escrow before it unlocks, sending the early exit penalty to the treasury.
+   * @returns
+   */
+  async exitEarly(): Promise<TransactionEnvelope> {
+    const lockerData = await this.lockerData();
+    const escrowData = await this.data();
+    const governorData = await this.sdk.programs.Govern.account.governor.fetch(
+      this.governorKey
+    );
+    const destinationTokens = await getOrCreateATA({
+      provider: this.provider,
+      mint: lockerData.tokenMint,
+      owner: escrowData.owner,
+    });
+    const treasuryTokens = await getOrCreateATA({
+      provider: this.provider,
+      mint: lockerData.tokenMint,
+      owner: governorData.smartWallet,
+    });
+    const [escrowHistory] = await findEscrowHistoryAddress(this.escrowKey);
+    const remainingAccounts = [
+      {
+        pubkey: escrowHistory,
+        isSigner: false,
+        isWritable: true,
+      },
+    ];
+    if (lockerData.historyEnabled) {
+      const [lockerHistory] = await findLockerHistoryAddress(this.locker);
+      remainingAccounts.push({
+        pubkey: lockerHistory,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+    return this.provider.newTX([
+      destinationTokens.instruction,
+      treasuryTokens.instruction,
+      this.lockerProgram.instruction.exitEarly({
+        accounts: {
+          locker: this.locker,
+          escrow: this.escrowKey,
+          escrowOwner: escrowData.owner,
+          escrowTokens: escrowData.tokens,
+          destinationTokens: destinationTokens.address,
+          governor: this.governorKey,
+          treasuryTokens: treasuryTokens.address,
+          payer: this.provider.wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        },
+        remainingAccounts,
+      }),
+    ]);
+  }
 }
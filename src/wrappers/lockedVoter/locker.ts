@@ -24,7 +24,11 @@ import type { TribecaSDK } from "../../sdk";
 import type { VoteSide } from "../../wrappers/govern/types";
 import { GovernorWrapper } from "../govern/governor";
 import { findWhitelistAddress } from ".";
-import { findEscrowAddress } from "./pda";
+import {
+  findEscrowAddress,
+  findEscrowHistoryAddress,
+  findLockerHistoryAddress,
+} from "./pda";
 
 /**
  * Helper methods around a Locked Voter electorate.
@@ -230,6 +234,24 @@ export class LockerWrapper {
       tokenProgram: TOKEN_PROGRAM_ID,
     };
 
+    const remainingAccounts = [];
+    if (lockerData.params.snapshotVotingEnabled) {
+      const [escrowHistory] = await findEscrowHistoryAddress(escrow);
+      remainingAccounts.push({
+        pubkey: escrowHistory,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+    if (lockerData.historyEnabled) {
+      const [lockerHistory] = await findLockerHistoryAddress(this.locker);
+      remainingAccounts.push({
+        pubkey: lockerHistory,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+
     if (lockerData.params.whitelistEnabled) {
       instructions.push(
         this.program.instruction.lockWithWhitelist(amount, duration, {
@@ -237,12 +259,14 @@ export class LockerWrapper {
             lock: lockAccounts,
             instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           },
+          remainingAccounts,
         })
       );
     } else {
       instructions.push(
         this.program.instruction.lockPermissionless(amount, duration, {
           accounts: lockAccounts,
+          remainingAccounts,
         })
       );
     }
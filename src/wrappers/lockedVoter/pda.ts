@@ -28,6 +28,38 @@ export const findEscrowAddress = async (
   );
 };
 
+export const findEscrowHistoryAddress = async (
+  escrow: PublicKey
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddress(
+    [utils.bytes.utf8.encode("EscrowHistory"), escrow.toBuffer()],
+    TRIBECA_ADDRESSES.LockedVoter
+  );
+};
+
+export const findEscrowExtenderAddress = async (
+  escrow: PublicKey,
+  extender: PublicKey
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddress(
+    [
+      utils.bytes.utf8.encode("EscrowExtender"),
+      escrow.toBuffer(),
+      extender.toBuffer(),
+    ],
+    TRIBECA_ADDRESSES.LockedVoter
+  );
+};
+
+export const findLockerHistoryAddress = async (
+  locker: PublicKey
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddress(
+    [utils.bytes.utf8.encode("LockerHistory"), locker.toBuffer()],
+    TRIBECA_ADDRESSES.LockedVoter
+  );
+};
+
 export const findWhitelistAddress = async (
   locker: PublicKey,
   programId: PublicKey,
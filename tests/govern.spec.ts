@@ -1,32 +1,89 @@
+import type { SmartWalletWrapper } from "@gokiprotocol/client";
 import { GokiSDK } from "@gokiprotocol/client";
 import { expectTX } from "@saberhq/chai-solana";
+import { TransactionEnvelope } from "@saberhq/solana-contrib";
+import {
+  createMint,
+  getOrCreateATA,
+  sleep,
+  SPLToken,
+  TOKEN_PROGRAM_ID,
+  u64,
+} from "@saberhq/token-utils";
 import type { SendTransactionError } from "@solana/web3.js";
-import { Keypair, PublicKey } from "@solana/web3.js";
-import type BN from "bn.js";
+import {
+  Keypair,
+  PublicKey,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import BN from "bn.js";
 import { expect } from "chai";
+import { createHash } from "crypto";
 import { zip } from "lodash";
 import invariant from "tiny-invariant";
 
-import { DEFAULT_VOTE_DELAY, DEFAULT_VOTE_PERIOD } from "../src";
+import type { GovernanceParameters, ProposalInstruction } from "../src";
+import { DEFAULT_VOTE_DELAY, DEFAULT_VOTE_PERIOD, VoteSide } from "../src";
 import type { GovernorWrapper } from "../src/wrappers/govern/governor";
 import {
   findGovernorAddress,
   findProposalAddress,
+  findProposalMetaAddress,
+  findVoteAddress,
+  findVoteRationaleAddress,
 } from "../src/wrappers/govern/pda";
-import { DUMMY_INSTRUCTIONS, makeSDK, setupGovernor, ZERO } from "./workspace";
+import {
+  DUMMY_INSTRUCTIONS,
+  executeTransactionBySmartWallet,
+  expectTXError,
+  makeSDK,
+  ONE,
+  setupGovernor,
+  ZERO,
+} from "./workspace";
+
+/**
+ * Hashes instructions as in `govern::hash_instructions`.
+ */
+const hashInstructions = (instructions: ProposalInstruction[]): Buffer => {
+  const encodeLength = (length: number): Buffer => {
+    const buf = Buffer.alloc(4);
+    buf.writeUInt32LE(length);
+    return buf;
+  };
+  const serialized = Buffer.concat([
+    encodeLength(instructions.length),
+    ...instructions.map(({ programId, keys, data }) =>
+      Buffer.concat([
+        programId.toBuffer(),
+        encodeLength(keys.length),
+        ...keys.map(({ pubkey, isSigner, isWritable }) =>
+          Buffer.concat([
+            pubkey.toBuffer(),
+            Buffer.from([isSigner ? 1 : 0, isWritable ? 1 : 0]),
+          ])
+        ),
+        encodeLength(data.length),
+        data,
+      ])
+    ),
+  ]);
+  return createHash("sha256").update(serialized).digest();
+};
 
 describe("Govern", () => {
   const sdk = makeSDK();
   const gokiSDK = GokiSDK.load({ provider: sdk.provider });
 
+  const electorate = Keypair.generate();
+
   let governorW: GovernorWrapper;
   let smartWallet: PublicKey;
 
   before(async () => {
     const owners = [sdk.provider.wallet.publicKey];
-    const electorate = Keypair.generate().publicKey;
     const { governorWrapper, smartWalletWrapper } = await setupGovernor({
-      electorate,
+      electorate: electorate.publicKey,
       sdk,
       gokiSDK,
       owners,
@@ -146,6 +203,1021 @@ describe("Govern", () => {
         expect(metadata.descriptionLink).to.be.equal(expectedLink);
         expect(metadata.proposal).to.eqAddress(proposalKey);
       });
+
+      context("Update proposal meta", () => {
+        beforeEach("create proposal meta", async () => {
+          const createMetaTX = await governorW.createProposalMeta({
+            proposal: proposalKey,
+            title: "This is my Proposal",
+            descriptionLink: "https://tribeca.so",
+          });
+          await expectTX(createMetaTX, "creating proposal meta").to.be
+            .fulfilled;
+        });
+
+        it("Can update proposal meta while Draft", async () => {
+          const expectedTitle = "This is my Proposal, with a much longer title";
+          const expectedLink = "https://tribeca.so/proposals/my-proposal";
+          const updateMetaTX = await governorW.updateProposalMeta({
+            proposal: proposalKey,
+            title: expectedTitle,
+            descriptionLink: expectedLink,
+          });
+          await expectTX(updateMetaTX, "updating proposal meta").to.be
+            .fulfilled;
+          const metadata = await governorW.fetchProposalMeta(proposalKey);
+          expect(metadata.title).to.be.equal(expectedTitle);
+          expect(metadata.descriptionLink).to.be.equal(expectedLink);
+        });
+
+        it("Cannot update proposal meta if not proposer", async () => {
+          const fakeProposer = Keypair.generate();
+          const updateMetaTX = await governorW.updateProposalMeta({
+            proposer: fakeProposer.publicKey,
+            proposal: proposalKey,
+            title: "This is not my Proposal",
+            descriptionLink: "https://tribeca.so",
+          });
+          updateMetaTX.addSigners(fakeProposer);
+          await expectTX(updateMetaTX).to.be.rejected;
+        });
+
+        it("Cannot update proposal meta if not Draft", async () => {
+          await expectTX(
+            governorW.cancelProposal({ proposal: proposalKey }),
+            "cancel a proposal"
+          ).to.be.fulfilled;
+
+          const updateMetaTX = await governorW.updateProposalMeta({
+            proposal: proposalKey,
+            title: "This is my canceled Proposal",
+            descriptionLink: "https://tribeca.so",
+          });
+          await expectTXError(updateMetaTX, "ProposalNotDraft");
+        });
+      });
+    });
+
+    context("Amend proposal instructions", () => {
+      const newInstruction = new TransactionInstruction({
+        programId: Keypair.generate().publicKey,
+        keys: [
+          {
+            pubkey: Keypair.generate().publicKey,
+            isSigner: false,
+            isWritable: true,
+          },
+        ],
+        data: Buffer.from([1, 2, 3]),
+      });
+
+      it("Can replace instructions while Draft", async () => {
+        await expectTX(
+          await governorW.amendProposalInstructions({
+            proposal: proposalKey,
+            instructions: [newInstruction],
+            append: false,
+          }),
+          "replace instructions"
+        ).to.be.fulfilled;
+
+        const proposalData = await governorW.fetchProposalByKey(proposalKey);
+        expect(proposalData.instructions).to.have.lengthOf(1);
+        expect(proposalData.instructions[0]).eql(newInstruction);
+        expect(proposalData.amendedAt).to.be.bignumber.greaterThan(ZERO);
+      });
+
+      it("Can append instructions while Draft", async () => {
+        await expectTX(
+          await governorW.amendProposalInstructions({
+            proposal: proposalKey,
+            instructions: [newInstruction],
+            append: true,
+          }),
+          "append instructions"
+        ).to.be.fulfilled;
+
+        const proposalData = await governorW.fetchProposalByKey(proposalKey);
+        zip(proposalData.instructions, [
+          ...DUMMY_INSTRUCTIONS,
+          newInstruction,
+        ]).map(([actual, expected]) => {
+          invariant(expected);
+          expect(actual).eql(expected);
+        });
+      });
+
+      it("Cannot amend instructions if not proposer", async () => {
+        const fakeProposer = Keypair.generate();
+        const tx = await governorW.amendProposalInstructions({
+          proposal: proposalKey,
+          proposer: fakeProposer.publicKey,
+          instructions: [newInstruction],
+          append: false,
+        });
+        tx.addSigners(fakeProposer);
+        await expectTX(tx).to.be.rejected;
+      });
+
+      it("Cannot amend instructions if not Draft", async () => {
+        await expectTX(
+          governorW.cancelProposal({ proposal: proposalKey }),
+          "cancel a proposal"
+        ).to.be.fulfilled;
+
+        await expectTXError(
+          await governorW.amendProposalInstructions({
+            proposal: proposalKey,
+            instructions: [newInstruction],
+            append: false,
+          }),
+          "ProposalNotDraft"
+        );
+      });
+    });
+
+    context("Vote rationale", () => {
+      const contentHash = createHash("sha256")
+        .update("I support this proposal.")
+        .digest();
+
+      let voteKey: PublicKey;
+
+      beforeEach("create a vote and its rationale", async () => {
+        const { voteKey: key, instruction } = await governorW.getOrCreateVote({
+          proposal: proposalKey,
+        });
+        await expectTX(sdk.provider.newTX([instruction]), "create a vote").to
+          .be.fulfilled;
+        await expectTX(
+          await governorW.createVoteRationale({ vote: key }),
+          "create a vote rationale"
+        ).to.be.fulfilled;
+        voteKey = key;
+      });
+
+      it("Vote rationale was initialized", async () => {
+        const voteRationale = await governorW.fetchVoteRationale(voteKey);
+        expect(voteRationale.vote).to.eqAddress(voteKey);
+        expect(voteRationale.payer).to.eqAddress(sdk.provider.wallet.publicKey);
+        expect(voteRationale.contentHash).to.eql(Array(32).fill(0));
+        expect(voteRationale.uri).to.equal("");
+      });
+
+      it("Voter can set vote rationale", async () => {
+        const uri = "https://tribeca.so/rationale";
+        await expectTX(
+          await governorW.setVoteRationale({
+            proposal: proposalKey,
+            vote: voteKey,
+            contentHash,
+            uri,
+          }),
+          "set vote rationale"
+        ).to.be.fulfilled;
+
+        const voteRationale = await governorW.fetchVoteRationale(voteKey);
+        expect(voteRationale.contentHash).to.eql([...contentHash]);
+        expect(voteRationale.uri).to.equal(uri);
+      });
+
+      it("Electorate can set vote rationale", async () => {
+        const uri = "https://tribeca.so/delegate-rationale";
+        const tx = await governorW.setVoteRationale({
+          proposal: proposalKey,
+          vote: voteKey,
+          contentHash,
+          uri,
+          authority: electorate.publicKey,
+        });
+        tx.addSigners(electorate);
+        await expectTX(tx, "set vote rationale as electorate").to.be
+          .fulfilled;
+
+        const voteRationale = await governorW.fetchVoteRationale(voteKey);
+        expect(voteRationale.uri).to.equal(uri);
+      });
+
+      it("Cannot set vote rationale if not voter or electorate", async () => {
+        const fakeAuthority = Keypair.generate();
+        const tx = await governorW.setVoteRationale({
+          proposal: proposalKey,
+          vote: voteKey,
+          contentHash,
+          uri: "https://tribeca.so/rationale",
+          authority: fakeAuthority.publicKey,
+        });
+        tx.addSigners(fakeAuthority);
+        await expectTXError(tx, "UnauthorizedVoteRationaleAuthority");
+      });
+
+      it("Cannot set a vote rationale URI longer than the maximum", async () => {
+        await expectTXError(
+          await governorW.setVoteRationale({
+            proposal: proposalKey,
+            vote: voteKey,
+            contentHash,
+            uri: "a".repeat(201),
+          }),
+          "VoteRationaleTooLong"
+        );
+      });
+    });
+  });
+
+  describe("Guardian", () => {
+    const guardianElectorate = Keypair.generate();
+    const guardian = Keypair.generate();
+
+    let guardedGovernorW: GovernorWrapper;
+    let guardedSmartWalletW: SmartWalletWrapper;
+    let proposalKey: PublicKey;
+    let proposalIndex: BN;
+
+    const setGuardian = async (newGuardian: PublicKey): Promise<void> => {
+      await executeTransactionBySmartWallet({
+        provider: sdk.provider,
+        smartWalletWrapper: guardedSmartWalletW,
+        instructions: [await guardedGovernorW.setGuardianIx(newGuardian)],
+      });
+    };
+
+    const vetoProposal = async (
+      vetoer: Keypair = guardian
+    ): Promise<TransactionEnvelope> => {
+      const tx = await guardedGovernorW.vetoProposal({
+        proposal: proposalKey,
+        guardian: vetoer.publicKey,
+      });
+      tx.addSigners(vetoer);
+      return tx;
+    };
+
+    const activateProposal = async (): Promise<void> => {
+      const tx = guardedGovernorW.activateProposal({
+        proposal: proposalKey,
+        electorate: guardianElectorate.publicKey,
+      });
+      tx.addSigners(guardianElectorate);
+      await expectTX(tx, "activate the proposal").to.be.fulfilled;
+    };
+
+    const passProposal = async (): Promise<void> => {
+      await activateProposal();
+      const tx = await guardedGovernorW.setVote({
+        proposal: proposalKey,
+        side: VoteSide.For,
+        weight: ONE,
+        electorate: guardianElectorate.publicKey,
+      });
+      tx.addSigners(guardianElectorate);
+      await expectTX(tx, "vote for the proposal").to.be.fulfilled;
+      await sleep(2_500); // sleep to end voting
+    };
+
+    before(async () => {
+      const owners = [sdk.provider.wallet.publicKey];
+      const { governorWrapper, smartWalletWrapper } = await setupGovernor({
+        electorate: guardianElectorate.publicKey,
+        sdk,
+        gokiSDK,
+        owners,
+        quorumVotes: ONE,
+        votingDelay: ZERO,
+        votingPeriod: new BN(2),
+      });
+      guardedGovernorW = governorWrapper;
+      guardedSmartWalletW = smartWalletWrapper;
+    });
+
+    beforeEach("set the guardian and create a proposal", async () => {
+      await setGuardian(guardian.publicKey);
+      const { index, proposal, tx } = await guardedGovernorW.createProposal({
+        instructions: DUMMY_INSTRUCTIONS,
+      });
+      await expectTX(tx, "create a proposal").to.be.fulfilled;
+      proposalIndex = index;
+      proposalKey = proposal;
+    });
+
+    it("Guardian was set", async () => {
+      const governorData = await guardedGovernorW.reload();
+      expect(governorData.guardian).to.eqAddress(guardian.publicKey);
+    });
+
+    it("Guardian can veto a Draft proposal", async () => {
+      await expectTX(await vetoProposal(), "veto the proposal").to.be
+        .fulfilled;
+      const proposalData = await guardedGovernorW.fetchProposalByKey(
+        proposalKey
+      );
+      expect(proposalData.vetoedAt).to.be.bignumber.greaterThan(ZERO);
+    });
+
+    it("Guardian can veto an Active proposal", async () => {
+      await activateProposal();
+      await expectTX(await vetoProposal(), "veto the proposal").to.be
+        .fulfilled;
+      const proposalData = await guardedGovernorW.fetchProposalByKey(
+        proposalKey
+      );
+      expect(proposalData.vetoedAt).to.be.bignumber.greaterThan(ZERO);
+    });
+
+    it("Guardian can veto a Succeeded proposal", async () => {
+      await passProposal();
+      await expectTX(await vetoProposal(), "veto the proposal").to.be
+        .fulfilled;
+      await expectTXError(
+        await guardedGovernorW.queueProposal({ index: proposalIndex }),
+        "ProposalNotQueueable"
+      );
+    });
+
+    it("Guardian can veto a Queued proposal", async () => {
+      await passProposal();
+      await expectTX(
+        await guardedGovernorW.queueProposal({ index: proposalIndex }),
+        "queue the proposal"
+      ).to.be.fulfilled;
+      await expectTX(await vetoProposal(), "veto the proposal").to.be
+        .fulfilled;
+      const proposalData = await guardedGovernorW.fetchProposalByKey(
+        proposalKey
+      );
+      expect(proposalData.vetoedAt).to.be.bignumber.greaterThan(ZERO);
+    });
+
+    it("Cannot veto a vetoed proposal", async () => {
+      await expectTX(await vetoProposal(), "veto the proposal").to.be
+        .fulfilled;
+      await expectTXError(await vetoProposal(), "ProposalNotVetoable");
+    });
+
+    it("Cannot veto if not guardian", async () => {
+      const tx = await vetoProposal(Keypair.generate());
+      await expectTX(tx).to.be.rejected;
+    });
+
+    it("Rotated guardian replaces the previous guardian", async () => {
+      const newGuardian = Keypair.generate();
+      await setGuardian(newGuardian.publicKey);
+      const governorData = await guardedGovernorW.reload();
+      expect(governorData.guardian).to.eqAddress(newGuardian.publicKey);
+
+      await expectTX(await vetoProposal(guardian)).to.be.rejected;
+      await expectTX(await vetoProposal(newGuardian), "veto the proposal").to
+        .be.fulfilled;
+    });
+
+    it("Cannot veto once the guardian is removed", async () => {
+      await setGuardian(PublicKey.default);
+      const governorData = await guardedGovernorW.reload();
+      expect(governorData.guardian).to.eqAddress(PublicKey.default);
+
+      await expectTXError(await vetoProposal(), "GuardianNotSet");
+    });
+  });
+
+  describe("Staged proposal", () => {
+    // two of the DUMMY_INSTRUCTIONS, which have no keys or data
+    const CAPACITY = 2 * (32 + 4 + 4);
+
+    let proposalKey: PublicKey;
+
+    beforeEach("create a staged proposal", async () => {
+      const { proposal, tx } = await governorW.createStagedProposal({
+        capacity: CAPACITY,
+      });
+      await expectTX(tx, "create a staged proposal").to.be.fulfilled;
+      proposalKey = proposal;
+    });
+
+    it("Staged proposal was initialized", async () => {
+      const proposalData = await governorW.fetchProposalByKey(proposalKey);
+      expect(proposalData.sealed).to.equal(false);
+      expect(proposalData.instructions).to.have.lengthOf(0);
+      expect(proposalData.proposer).to.eqAddress(
+        sdk.provider.wallet.publicKey
+      );
+    });
+
+    it("Can append instructions up to the capacity", async () => {
+      for (const instruction of DUMMY_INSTRUCTIONS.slice(0, 2)) {
+        await expectTX(
+          governorW.appendProposalInstructions({
+            proposal: proposalKey,
+            instructions: [instruction],
+          }),
+          "append an instruction"
+        ).to.be.fulfilled;
+      }
+
+      const proposalData = await governorW.fetchProposalByKey(proposalKey);
+      zip(proposalData.instructions, DUMMY_INSTRUCTIONS.slice(0, 2)).map(
+        ([actual, expected]) => {
+          invariant(expected);
+          expect(actual).eql(expected);
+        }
+      );
+    });
+
+    it("Cannot append instructions beyond the capacity", async () => {
+      await expectTXError(
+        governorW.appendProposalInstructions({
+          proposal: proposalKey,
+          instructions: DUMMY_INSTRUCTIONS,
+        }),
+        "ProposalCapacityExceeded"
+      );
+    });
+
+    it("Cannot append instructions if not proposer", async () => {
+      const fakeProposer = Keypair.generate();
+      const tx = governorW.appendProposalInstructions({
+        proposal: proposalKey,
+        proposer: fakeProposer.publicKey,
+        instructions: DUMMY_INSTRUCTIONS.slice(0, 1),
+      });
+      tx.addSigners(fakeProposer);
+      await expectTX(tx).to.be.rejected;
+    });
+
+    it("Cannot append instructions after sealing", async () => {
+      await expectTX(
+        governorW.appendProposalInstructions({
+          proposal: proposalKey,
+          instructions: DUMMY_INSTRUCTIONS.slice(0, 1),
+        }),
+        "append an instruction"
+      ).to.be.fulfilled;
+      await expectTX(
+        await governorW.sealProposal({ proposal: proposalKey }),
+        "seal the proposal"
+      ).to.be.fulfilled;
+
+      const proposalData = await governorW.fetchProposalByKey(proposalKey);
+      expect(proposalData.sealed).to.equal(true);
+
+      await expectTXError(
+        governorW.appendProposalInstructions({
+          proposal: proposalKey,
+          instructions: DUMMY_INSTRUCTIONS.slice(1, 2),
+        }),
+        "ProposalAlreadySealed"
+      );
+    });
+
+    it("Cannot activate an unsealed proposal", async () => {
+      const tx = governorW.activateProposal({
+        proposal: proposalKey,
+        electorate: electorate.publicKey,
+      });
+      tx.addSigners(electorate);
+      await expectTXError(tx, "ProposalNotSealed");
+    });
+  });
+
+  describe("Committed proposal", () => {
+    const committedElectorate = Keypair.generate();
+    const overriddenProgramId = Keypair.generate().publicKey;
+
+    let committedGovernorW: GovernorWrapper;
+    let committedSmartWalletW: SmartWalletWrapper;
+
+    before(async () => {
+      const owners = [sdk.provider.wallet.publicKey];
+      const { governorWrapper, smartWalletWrapper } = await setupGovernor({
+        electorate: committedElectorate.publicKey,
+        sdk,
+        gokiSDK,
+        owners,
+        quorumVotes: ONE,
+        votingDelay: ZERO,
+        votingPeriod: new BN(2),
+      });
+      committedGovernorW = governorWrapper;
+      committedSmartWalletW = smartWalletWrapper;
+
+      await executeTransactionBySmartWallet({
+        provider: sdk.provider,
+        smartWalletWrapper: committedSmartWalletW,
+        instructions: [
+          await committedGovernorW.createParameterOverrideIx(
+            overriddenProgramId,
+            {
+              votingPeriod: new BN(100),
+              quorumVotes: ZERO,
+              quorumBps: 0,
+              approvalThresholdBps: 0,
+              timelockDelaySeconds: ZERO,
+            }
+          ),
+        ],
+      });
+    });
+
+    /**
+     * Creates a committed proposal which has succeeded.
+     */
+    const createSucceededProposal = async (
+      instructions: ProposalInstruction[],
+      invokedProgramIds: PublicKey[]
+    ): Promise<{ proposal: PublicKey; index: BN }> => {
+      const { proposal, index, tx } =
+        await committedGovernorW.createCommittedProposal({
+          instructionsHash: hashInstructions(instructions),
+          invokedProgramIds,
+        });
+      await expectTX(tx, "create a committed proposal").to.be.fulfilled;
+
+      const activateTX = committedGovernorW.activateProposal({
+        proposal,
+        electorate: committedElectorate.publicKey,
+      });
+      activateTX.addSigners(committedElectorate);
+      await expectTX(activateTX, "activate the proposal").to.be.fulfilled;
+
+      const voteTX = await committedGovernorW.setVote({
+        proposal,
+        side: VoteSide.For,
+        weight: ONE,
+        electorate: committedElectorate.publicKey,
+      });
+      voteTX.addSigners(committedElectorate);
+      await expectTX(voteTX, "vote for the proposal").to.be.fulfilled;
+
+      await sleep(2_500); // sleep to end voting
+      return { proposal, index };
+    };
+
+    it("Can queue with the committed instructions", async () => {
+      const { proposal, index } = await createSucceededProposal(
+        DUMMY_INSTRUCTIONS,
+        DUMMY_INSTRUCTIONS.map(({ programId }) => programId)
+      );
+      const proposalData = await committedGovernorW.fetchProposalByKey(
+        proposal
+      );
+      expect(proposalData.instructions).to.have.lengthOf(0);
+
+      await expectTX(
+        await committedGovernorW.queueCommittedProposal({
+          index,
+          instructions: DUMMY_INSTRUCTIONS,
+        }),
+        "queue the committed proposal"
+      ).to.be.fulfilled;
+    });
+
+    it("Cannot queue with instructions not matching the hash", async () => {
+      const { index } = await createSucceededProposal(
+        DUMMY_INSTRUCTIONS,
+        DUMMY_INSTRUCTIONS.map(({ programId }) => programId)
+      );
+      await expectTXError(
+        await committedGovernorW.queueCommittedProposal({
+          index,
+          instructions: DUMMY_INSTRUCTIONS.slice(0, 2),
+        }),
+        "InvalidInstructionsHash"
+      );
+    });
+
+    it("Cannot queue if the parameters are less strict than the overrides", async () => {
+      const instructions = [
+        new TransactionInstruction({
+          programId: overriddenProgramId,
+          keys: [],
+        }),
+      ];
+      // the proposer does not declare the overridden program
+      const { index } = await createSucceededProposal(instructions, []);
+      await expectTXError(
+        await committedGovernorW.queueCommittedProposal({
+          index,
+          instructions,
+        }),
+        "InvalidParameterOverrides"
+      );
+    });
+
+    it("Cannot queue without revealing the instructions", async () => {
+      const { index } = await createSucceededProposal(
+        DUMMY_INSTRUCTIONS,
+        DUMMY_INSTRUCTIONS.map(({ programId }) => programId)
+      );
+      await expectTXError(
+        await committedGovernorW.queueProposal({ index }),
+        "ProposalInstructionsCommitted"
+      );
+    });
+  });
+
+  describe("Close", () => {
+    const closeElectorate = Keypair.generate();
+    const GRACE_PERIOD_SECONDS = 4;
+
+    let closeGovernorW: GovernorWrapper;
+    let closeSmartWalletW: SmartWalletWrapper;
+
+    const executeBySmartWallet = async (
+      instructions: TransactionInstruction[]
+    ): Promise<void> => {
+      await executeTransactionBySmartWallet({
+        provider: sdk.provider,
+        smartWalletWrapper: closeSmartWalletW,
+        instructions,
+      });
+    };
+
+    const setParams = async (
+      params: Partial<GovernanceParameters>
+    ): Promise<void> => {
+      const governorData = await closeGovernorW.reload();
+      await executeBySmartWallet([
+        await closeGovernorW.setGovernanceParamsIx({
+          ...governorData.params,
+          ...params,
+        }),
+      ]);
+    };
+
+    /**
+     * Creates a proposal with a ProposalMeta.
+     */
+    const createProposal = async (
+      instructions: ProposalInstruction[] = DUMMY_INSTRUCTIONS
+    ): Promise<{ proposal: PublicKey; index: BN }> => {
+      const { proposal, index, tx } = await closeGovernorW.createProposal({
+        instructions,
+      });
+      await expectTX(tx, "create a proposal").to.be.fulfilled;
+      await expectTX(
+        await closeGovernorW.createProposalMeta({
+          proposal,
+          title: "Proposal",
+          descriptionLink: "https://tribeca.so",
+        }),
+        "create proposal meta"
+      ).to.be.fulfilled;
+      return { proposal, index };
+    };
+
+    const activateProposal = async (
+      proposal: PublicKey,
+      activeProposalCounter?: PublicKey
+    ): Promise<void> => {
+      const tx = closeGovernorW.activateProposal({
+        proposal,
+        electorate: closeElectorate.publicKey,
+        activeProposalCounter,
+      });
+      tx.addSigners(closeElectorate);
+      await expectTX(tx, "activate the proposal").to.be.fulfilled;
+    };
+
+    /**
+     * Votes on a proposal and explains the vote with a VoteRationale.
+     */
+    const voteOnProposal = async (
+      proposal: PublicKey,
+      side: VoteSide
+    ): Promise<void> => {
+      const tx = await closeGovernorW.setVote({
+        proposal,
+        side,
+        weight: ONE,
+        electorate: closeElectorate.publicKey,
+      });
+      tx.addSigners(closeElectorate);
+      await expectTX(tx, "vote on the proposal").to.be.fulfilled;
+
+      const [vote] = await findVoteAddress(
+        proposal,
+        sdk.provider.wallet.publicKey
+      );
+      await expectTX(
+        await closeGovernorW.createVoteRationale({ vote }),
+        "create vote rationale"
+      ).to.be.fulfilled;
+    };
+
+    const passProposal = async (proposal: PublicKey): Promise<void> => {
+      await activateProposal(proposal);
+      await voteOnProposal(proposal, VoteSide.For);
+      await sleep(2_500); // sleep to end voting
+    };
+
+    const expectClosed = async (proposal: PublicKey): Promise<void> => {
+      const [proposalMeta] = await findProposalMetaAddress(proposal);
+      const [vote] = await findVoteAddress(
+        proposal,
+        sdk.provider.wallet.publicKey
+      );
+      const [voteRationale] = await findVoteRationaleAddress(vote);
+      for (const key of [proposal, proposalMeta, vote, voteRationale]) {
+        const accountInfo = await sdk.provider.connection.getAccountInfo(key);
+        expect(accountInfo, key.toString()).to.equal(null);
+      }
+    };
+
+    before(async () => {
+      const owners = [sdk.provider.wallet.publicKey];
+      const { governorWrapper, smartWalletWrapper } = await setupGovernor({
+        electorate: closeElectorate.publicKey,
+        sdk,
+        gokiSDK,
+        owners,
+        quorumVotes: ONE,
+        votingDelay: ZERO,
+        votingPeriod: new BN(2),
+      });
+      closeGovernorW = governorWrapper;
+      closeSmartWalletW = smartWalletWrapper;
+
+      await setParams({ gracePeriodSeconds: new BN(GRACE_PERIOD_SECONDS) });
+      await executeBySmartWallet([
+        await closeGovernorW.setGuardianIx(sdk.provider.wallet.publicKey),
+      ]);
+    });
+
+    it("Cannot close a Draft proposal", async () => {
+      const { proposal } = await createProposal();
+      await expectTXError(
+        await closeGovernorW.closeProposal(proposal),
+        "ProposalNotFinal"
+      );
+      await expectTXError(
+        await closeGovernorW.closeProposalMeta(proposal),
+        "ProposalNotFinal"
+      );
+    });
+
+    it("Close a Canceled proposal", async () => {
+      const { proposal } = await createProposal();
+      await expectTX(
+        closeGovernorW.cancelProposal({ proposal }),
+        "cancel the proposal"
+      ).to.be.fulfilled;
+
+      await expectTX(
+        await closeGovernorW.closeProposal(proposal),
+        "close the proposal"
+      ).to.be.fulfilled;
+      // the meta may be closed after its proposal
+      await expectTX(
+        await closeGovernorW.closeProposalMeta(proposal),
+        "close the proposal meta"
+      ).to.be.fulfilled;
+
+      const [proposalMeta] = await findProposalMetaAddress(proposal);
+      for (const key of [proposal, proposalMeta]) {
+        const accountInfo = await sdk.provider.connection.getAccountInfo(key);
+        expect(accountInfo, key.toString()).to.equal(null);
+      }
+    });
+
+    it("Cannot close an Active proposal or its votes", async () => {
+      const { proposal } = await createProposal();
+      await activateProposal(proposal);
+      await voteOnProposal(proposal, VoteSide.For);
+
+      await expectTXError(
+        await closeGovernorW.closeProposal(proposal),
+        "ProposalNotFinal"
+      );
+      await expectTXError(
+        await closeGovernorW.closeVote({ proposal }),
+        "ProposalNotFinal"
+      );
+      await expectTXError(
+        await closeGovernorW.closeVoteRationale({ proposal }),
+        "ProposalNotFinal"
+      );
+    });
+
+    it("Close a Defeated proposal and its votes", async () => {
+      const { proposal } = await createProposal();
+      await activateProposal(proposal);
+      await voteOnProposal(proposal, VoteSide.Against);
+      await sleep(2_500); // sleep to end voting
+
+      await expectTX(
+        await closeGovernorW.closeProposal(proposal),
+        "close the proposal"
+      ).to.be.fulfilled;
+      await expectTX(
+        await closeGovernorW.closeProposalMeta(proposal),
+        "close the proposal meta"
+      ).to.be.fulfilled;
+      await expectTX(
+        await closeGovernorW.closeVoteRationale({ proposal }),
+        "close the vote rationale"
+      ).to.be.fulfilled;
+      await expectTX(
+        await closeGovernorW.closeVote({ proposal }),
+        "close the vote"
+      ).to.be.fulfilled;
+      await expectClosed(proposal);
+    });
+
+    it("Close a Vetoed proposal", async () => {
+      const { proposal } = await createProposal();
+      await activateProposal(proposal);
+      await voteOnProposal(proposal, VoteSide.For);
+      await expectTX(
+        await closeGovernorW.vetoProposal({ proposal }),
+        "veto the proposal"
+      ).to.be.fulfilled;
+
+      await expectTX(
+        await closeGovernorW.closeVoteRationale({ proposal }),
+        "close the vote rationale"
+      ).to.be.fulfilled;
+      await expectTX(
+        await closeGovernorW.closeVote({ proposal }),
+        "close the vote"
+      ).to.be.fulfilled;
+      await expectTX(
+        await closeGovernorW.closeProposal(proposal),
+        "close the proposal"
+      ).to.be.fulfilled;
+      await expectTX(
+        await closeGovernorW.closeProposalMeta(proposal),
+        "close the proposal meta"
+      ).to.be.fulfilled;
+      await expectClosed(proposal);
+    });
+
+    it("Close an Expired proposal", async () => {
+      const { proposal } = await createProposal();
+      await passProposal(proposal);
+      await expectTXError(
+        await closeGovernorW.closeProposal(proposal),
+        "ProposalNotFinal"
+      );
+
+      await sleep(GRACE_PERIOD_SECONDS * 1_000); // sleep until expired
+      await expectTX(
+        await closeGovernorW.closeProposal(proposal),
+        "close the proposal"
+      ).to.be.fulfilled;
+      await expectTX(
+        await closeGovernorW.closeProposalMeta(proposal),
+        "close the proposal meta"
+      ).to.be.fulfilled;
+      await expectTX(
+        await closeGovernorW.closeVoteRationale({ proposal }),
+        "close the vote rationale"
+      ).to.be.fulfilled;
+      await expectTX(
+        await closeGovernorW.closeVote({ proposal }),
+        "close the vote"
+      ).to.be.fulfilled;
+      await expectClosed(proposal);
+    });
+
+    it("Cannot close a Queued proposal until its transaction is executed", async () => {
+      const { proposal, index } = await createProposal([
+        await closeGovernorW.setGuardianIx(sdk.provider.wallet.publicKey),
+      ]);
+      await passProposal(proposal);
+      await expectTX(
+        await closeGovernorW.queueProposal({ index }),
+        "queue the proposal"
+      ).to.be.fulfilled;
+
+      await expectTXError(
+        await closeGovernorW.closeProposal(proposal),
+        "ProposalNotFinal"
+      );
+      await expectTXError(
+        await closeGovernorW.closeVote({ proposal }),
+        "ProposalNotFinal"
+      );
+
+      const { queuedTransaction } = await closeGovernorW.fetchProposalByKey(
+        proposal
+      );
+      await expectTX(
+        await closeSmartWalletW.executeTransaction({
+          transactionKey: queuedTransaction,
+        }),
+        "execute the transaction"
+      ).to.be.fulfilled;
+
+      await expectTX(
+        await closeGovernorW.closeVoteRationale({ proposal }),
+        "close the vote rationale"
+      ).to.be.fulfilled;
+      await expectTX(
+        await closeGovernorW.closeVote({ proposal }),
+        "close the vote"
+      ).to.be.fulfilled;
+      await expectTX(
+        await closeGovernorW.closeProposalMeta(proposal),
+        "close the proposal meta"
+      ).to.be.fulfilled;
+      await expectTX(
+        await closeGovernorW.closeProposal(proposal),
+        "close the proposal"
+      ).to.be.fulfilled;
+      await expectClosed(proposal);
+    });
+
+    it("Cannot close a proposal with an unsettled deposit", async () => {
+      const { provider } = sdk;
+      const depositMint = await createMint(provider);
+      const depositAmount = new u64(1_000);
+      const ataInstructions: TransactionInstruction[] = [];
+      for (const owner of [
+        closeGovernorW.governorKey,
+        closeSmartWalletW.key,
+        provider.wallet.publicKey,
+      ]) {
+        const { address, instruction } = await getOrCreateATA({
+          provider,
+          mint: depositMint,
+          owner,
+        });
+        if (instruction) {
+          ataInstructions.push(instruction);
+        }
+        if (owner.equals(provider.wallet.publicKey)) {
+          ataInstructions.push(
+            SPLToken.createMintToInstruction(
+              TOKEN_PROGRAM_ID,
+              depositMint,
+              address,
+              provider.wallet.publicKey,
+              [],
+              depositAmount
+            )
+          );
+        }
+      }
+      await expectTX(
+        new TransactionEnvelope(provider, ataInstructions),
+        "create token accounts"
+      ).to.be.fulfilled;
+      await executeBySmartWallet([
+        await closeGovernorW.setProposalDepositIx(depositMint, depositAmount),
+      ]);
+      await closeGovernorW.reload();
+
+      const { proposal } = await createProposal();
+      await expectTX(
+        closeGovernorW.cancelProposal({ proposal }),
+        "cancel the proposal"
+      ).to.be.fulfilled;
+      await expectTXError(
+        await closeGovernorW.closeProposal(proposal),
+        "DepositNotSettled"
+      );
+
+      await expectTX(
+        await closeGovernorW.settleProposalDeposit(proposal),
+        "refund the deposit"
+      ).to.be.fulfilled;
+      await expectTX(
+        await closeGovernorW.closeProposal(proposal),
+        "close the proposal"
+      ).to.be.fulfilled;
+
+      await executeBySmartWallet([
+        await closeGovernorW.setProposalDepositIx(depositMint, ZERO),
+      ]);
+      await closeGovernorW.reload();
+    });
+
+    it("Cannot close a proposal counted as active", async () => {
+      await setParams({ maxActiveProposalsPerProposer: ONE });
+      const { activeProposalCounter, tx } =
+        await closeGovernorW.createActiveProposalCounter({
+          proposer: sdk.provider.wallet.publicKey,
+        });
+      await expectTX(tx, "create active proposal counter").to.be.fulfilled;
+
+      const { proposal } = await createProposal();
+      await activateProposal(proposal, activeProposalCounter);
+      await voteOnProposal(proposal, VoteSide.Against);
+      await sleep(2_500); // sleep to end voting
+
+      await expectTXError(
+        await closeGovernorW.closeProposal(proposal),
+        "ActiveProposalNotReleased"
+      );
+      await expectTX(
+        await closeGovernorW.releaseActiveProposal(proposal),
+        "release the proposal"
+      ).to.be.fulfilled;
+      await expectTX(
+        await closeGovernorW.closeProposal(proposal),
+        "close the proposal"
+      ).to.be.fulfilled;
+
+      await setParams({ maxActiveProposalsPerProposer: ZERO });
     });
   });
 });
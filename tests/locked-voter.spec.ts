@@ -50,6 +50,7 @@ import {
   createUser,
   DUMMY_INSTRUCTIONS,
   executeTransactionBySmartWallet,
+  expectTXError,
   INITIAL_MINT_AMOUNT,
   makeSDK,
   setupGovernor,
@@ -226,6 +227,23 @@ describe("Locked Voter", () => {
     await expectTX(lockTx, "lock tokens").to.be.fulfilled;
   });
 
+  it("Cannot enable snapshot voting with locked tokens", async () => {
+    const { transactionKey, tx } = await smartWalletW.newTransaction({
+      proposer: sdk.provider.wallet.publicKey,
+      instructions: [
+        await lockerW.setLockerParamsIx({
+          ...DEFAULT_LOCKER_PARAMS,
+          snapshotVotingEnabled: true,
+        }),
+      ],
+    });
+    await expectTX(tx, "create new transaction").to.be.fulfilled;
+    await expectTXError(
+      await smartWalletW.executeTransaction({ transactionKey }),
+      "LockerNotEmpty"
+    );
+  });
+
   describe("Escrow", () => {
     let user: Signer;
     let initialLockedSupply: BN;
@@ -363,6 +381,83 @@ describe("Locked Voter", () => {
         );
       }
     });
+
+    describe("Extender", () => {
+      let escrowW: VoteEscrow;
+      let extenderKP: Keypair;
+
+      beforeEach("Create escrow wrapper", async () => {
+        const [escrowKey] = await findEscrowAddress(
+          lockerW.locker,
+          user.publicKey
+        );
+        escrowW = new VoteEscrow(
+          sdk,
+          lockerW.locker,
+          governorW.governorKey,
+          escrowKey,
+          user.publicKey
+        );
+        extenderKP = Keypair.generate();
+      });
+
+      const approveExtender = async (maxEscrowEndsAt: BN): Promise<void> => {
+        const approveTx = await escrowW.approveExtender({
+          extender: extenderKP.publicKey,
+          maxEscrowEndsAt,
+        });
+        approveTx.addSigners(user);
+        await expectTX(approveTx, "approve extender").to.be.fulfilled;
+      };
+
+      const extendAsExtender = async (): Promise<TransactionEnvelope> => {
+        await sleep(2000); // sleep so the lock can be extended
+        const extendTx = await escrowW.extendLockDurationAsExtender({
+          duration: DEFAULT_LOCKER_PARAMS.maxStakeDuration,
+          extender: extenderKP.publicKey,
+        });
+        extendTx.addSigners(extenderKP);
+        return extendTx;
+      };
+
+      it("Extend lock duration as an approved extender", async () => {
+        const { escrowEndsAt } = await escrowW.data();
+        await approveExtender(escrowEndsAt.add(ONE_DAY));
+
+        const extendTx = await extendAsExtender();
+        await expectTX(extendTx, "extend as extender").to.be.fulfilled;
+        const escrowData = await lockerW.fetchEscrow(escrowW.escrowKey);
+        expect(escrowData.escrowEndsAt).to.bignumber.greaterThan(escrowEndsAt);
+        expect(escrowData.escrowEndsAt).to.bignumber.lte(
+          escrowEndsAt.add(ONE_DAY)
+        );
+      });
+
+      it("Extender cannot extend past the approved end", async () => {
+        const { escrowEndsAt } = await escrowW.data();
+        await approveExtender(escrowEndsAt);
+
+        await expectTXError(
+          await extendAsExtender(),
+          "EscrowExtenderLimitExceeded"
+        );
+      });
+
+      it("Unapproved extender cannot extend the lock", async () => {
+        await expectTXError(await extendAsExtender(), "AccountNotInitialized");
+      });
+
+      it("Revoked extender cannot extend the lock", async () => {
+        const { escrowEndsAt } = await escrowW.data();
+        await approveExtender(escrowEndsAt.add(ONE_DAY));
+
+        const revokeTx = await escrowW.revokeExtender(extenderKP.publicKey);
+        revokeTx.addSigners(user);
+        await expectTX(revokeTx, "revoke extender").to.be.fulfilled;
+
+        await expectTXError(await extendAsExtender(), "AccountNotInitialized");
+      });
+    });
   });
 
   it("Exit escrow", async () => {
@@ -410,6 +505,59 @@ describe("Locked Voter", () => {
     await expectLockedSupply(shortLockerW, ZERO);
   });
 
+  it("Withdraw part of an unlocked escrow", async () => {
+    const { governorKey } = governorW;
+    const { locker, tx } = await sdk.createLocker({
+      minStakeDuration: new BN(1),
+      proposalActivationMinVotes: INITIAL_MINT_AMOUNT,
+      governor: governorKey,
+      govTokenMint,
+    });
+    await expectTX(tx, "initialize locker").to.be.fulfilled;
+
+    const shortLockerW = await LockerWrapper.load(sdk, locker, governorKey);
+    const lockTx = await shortLockerW.lockTokens({
+      amount: INITIAL_MINT_AMOUNT,
+      duration: new BN(1),
+      authority: user.publicKey,
+    });
+    lockTx.addSigners(user);
+    await expectTX(lockTx, "short lock up").to.be.fulfilled;
+
+    const [escrowKey] = await findEscrowAddress(locker, user.publicKey);
+    const escrowW = new VoteEscrow(
+      sdk,
+      locker,
+      governorKey,
+      escrowKey,
+      user.publicKey
+    );
+    const halfAmount = INITIAL_MINT_AMOUNT.divn(2);
+
+    const earlyWithdrawTx = await escrowW.withdraw(halfAmount);
+    earlyWithdrawTx.addSigners(user);
+    await expectTXError(earlyWithdrawTx, "EscrowNotEnded");
+
+    await sleep(2500); // sleep to lockup
+    const withdrawTx = await escrowW.withdraw(halfAmount);
+    withdrawTx.addSigners(user);
+    await expectTX(withdrawTx, "withdraw half").to.be.fulfilled;
+
+    const escrowData = await shortLockerW.fetchEscrow(escrowKey);
+    expect(escrowData.amount).to.bignumber.eq(halfAmount);
+    const userATA = await getATAAddress({
+      mint: govTokenMint,
+      owner: user.publicKey,
+    });
+    const tokenAccount = await getTokenAccount(sdk.provider, userATA);
+    expect(tokenAccount.amount).to.bignumber.eq(halfAmount);
+    await expectLockedSupply(shortLockerW, halfAmount);
+
+    const overdrawTx = await escrowW.withdraw(INITIAL_MINT_AMOUNT);
+    overdrawTx.addSigners(user);
+    await expectTXError(overdrawTx, "InsufficientEscrowBalance");
+  });
+
   describe("Voting", () => {
     let user: Signer;
     let escrowW: VoteEscrow;
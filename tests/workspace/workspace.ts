@@ -15,13 +15,17 @@ import {
   TOKEN_PROGRAM_ID,
   u64,
 } from "@saberhq/token-utils";
-import type { PublicKey, Signer } from "@solana/web3.js";
+import type {
+  PublicKey,
+  SendTransactionError,
+  Signer,
+} from "@solana/web3.js";
 import {
   Keypair,
   LAMPORTS_PER_SOL,
   TransactionInstruction,
 } from "@solana/web3.js";
-import chai from "chai";
+import chai, { expect } from "chai";
 
 import type { TribecaPrograms } from "../../src";
 import { TribecaSDK } from "../../src";
@@ -160,3 +164,20 @@ export const executeTransactionBySmartWallet = async ({
 
   return transactionKey;
 };
+
+/**
+ * Asserts that a transaction fails with the given Anchor error code.
+ */
+export const expectTXError = async (
+  tx: TransactionEnvelope,
+  errorCode: string
+): Promise<void> => {
+  try {
+    await tx.confirm();
+  } catch (e) {
+    const error = e as SendTransactionError;
+    expect(error.logs?.join("\n")).to.include(`Error Code: ${errorCode}.`);
+    return;
+  }
+  expect.fail(`transaction should have failed with ${errorCode}`);
+};